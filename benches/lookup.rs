@@ -0,0 +1,85 @@
+//! Built-in lookup tables (static sorted arrays) against a custom table with the same entries
+//! (hash maps, as the built-in tables were stored before). Run with `cargo bench`.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use oddsidizer::{
+    ConversionConfig, LookupEntry, LookupTable, american_to_decimal_custom,
+    american_to_decimal_explained, american_to_fractional_custom, decimal_to_fractional_custom,
+    lookup_decimal_to_fraction_with_config,
+};
+use rust_decimal::Decimal;
+
+/// Decimal odds from 1.01 to 20, some of them in the basic table.
+fn decimals() -> Vec<Decimal> {
+    (101..=2000).map(|value| Decimal::new(value, 2)).collect()
+}
+
+fn americans() -> Vec<i32> {
+    (-1000..=-100).chain(100..=1000).collect()
+}
+
+/// Configs with the built-in basic tables and with a custom table holding the same entries.
+fn configs() -> [(&'static str, ConversionConfig); 2] {
+    let basic = ConversionConfig::default();
+    let entries = americans().into_iter().filter_map(|american| {
+        let decimal = american_to_decimal_explained(american, &basic).ok()?;
+        decimal.is_lookup().then(|| LookupEntry {
+            decimal: decimal.value,
+            american,
+            fraction: american_to_fractional_custom(american, &basic).unwrap(),
+        })
+    });
+    let custom =
+        ConversionConfig::default().custom_lookup(LookupTable::from_entries(entries).unwrap());
+
+    [("static", basic), ("hash_map", custom)]
+}
+
+fn bench_lookup(c: &mut Criterion) {
+    let decimals = decimals();
+    let americans = americans();
+
+    let mut group = c.benchmark_group("lookup_decimal_to_fraction");
+    for (name, config) in configs() {
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                for decimal in &decimals {
+                    black_box(lookup_decimal_to_fraction_with_config(
+                        black_box(*decimal),
+                        &config,
+                    ));
+                }
+            })
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("decimal_to_fractional");
+    for (name, config) in configs() {
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                for decimal in &decimals {
+                    black_box(decimal_to_fractional_custom(black_box(*decimal), &config).ok());
+                }
+            })
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("american_to_decimal");
+    for (name, config) in configs() {
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                for american in &americans {
+                    black_box(american_to_decimal_custom(black_box(*american), &config).ok());
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_lookup);
+criterion_main!(benches);
@@ -0,0 +1,160 @@
+use rust_decimal::Decimal;
+
+use crate::{CalculationError, Odds};
+
+/// Expected value of a bet per unit stake, given the true probability of winning.
+///
+/// F. ex. `0.05` means 5% expected profit on the stake.
+pub fn expected_value(probability: Decimal, odds: &Odds) -> Result<Decimal, CalculationError> {
+    if probability < Decimal::ZERO || probability > Decimal::ONE {
+        return Err(CalculationError::InvalidProbability);
+    }
+
+    Ok(probability * odds.exact_decimal() - Decimal::ONE)
+}
+
+/// Remove bookmaker's margin from the market by normalizing implied probabilities to sum up to 1.
+pub fn remove_vig(market: &[Odds]) -> Result<Vec<Decimal>, CalculationError> {
+    let probabilities: Vec<_> = market.iter().map(Odds::implied_probability).collect();
+
+    let book: Decimal = probabilities.iter().sum();
+
+    Ok(probabilities.into_iter().map(|p| p / book).collect())
+}
+
+/// Comparison of the price taken with the closing price.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClosingLineValue {
+    /// Relative difference of decimal prices, f. ex. `0.1` if the price taken was 10% bigger than the closing one.
+    pub percentage: Decimal,
+    /// Closing implied probability minus implied probability of the price taken (positive when the line was beaten).
+    pub implied_probability_difference: Decimal,
+}
+
+/// Compute closing line value of the price taken against the closing price.
+pub fn closing_line_value(
+    taken: &Odds,
+    closing: &Odds,
+) -> Result<ClosingLineValue, CalculationError> {
+    clv_from_probability(taken, closing.implied_probability())
+}
+
+/// Compute closing line value against the closing price of `selection` with the margin removed from the closing market.
+pub fn closing_line_value_devigged(
+    taken: &Odds,
+    closing_market: &[Odds],
+    selection: usize,
+) -> Result<ClosingLineValue, CalculationError> {
+    let fair = remove_vig(closing_market)?;
+    let closing_probability = fair
+        .get(selection)
+        .copied()
+        .ok_or(CalculationError::InvalidSelection)?;
+
+    clv_from_probability(taken, closing_probability)
+}
+
+fn clv_from_probability(
+    taken: &Odds,
+    closing_probability: Decimal,
+) -> Result<ClosingLineValue, CalculationError> {
+    let taken_decimal = taken.exact_decimal();
+
+    Ok(ClosingLineValue {
+        percentage: taken_decimal * closing_probability - Decimal::ONE,
+        implied_probability_difference: closing_probability - Decimal::ONE / taken_decimal,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use crate::testing_helpers::assert_decimal_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_expected_value() {
+        assert_decimal_eq(
+            expected_value(dec!(0.5), &Odds::decimal(dec!(2.2)).unwrap()).unwrap(),
+            dec!(0.1),
+        );
+        assert_decimal_eq(
+            expected_value(dec!(0.25), &Odds::fractional(5, 2).unwrap()).unwrap(),
+            dec!(-0.125),
+        );
+        assert_decimal_eq(
+            expected_value(dec!(0.55), &Odds::american(-110).unwrap()).unwrap(),
+            dec!(0.05),
+        );
+
+        assert_eq!(
+            expected_value(dec!(1.1), &Odds::decimal(dec!(2.0)).unwrap()),
+            Err(CalculationError::InvalidProbability)
+        );
+    }
+
+    #[test]
+    fn test_remove_vig() {
+        // 10/11 both ways
+        let market = [
+            Odds::american(-110).unwrap(),
+            Odds::fractional(10, 11).unwrap(),
+        ];
+        let fair = remove_vig(&market).unwrap();
+
+        assert_decimal_eq(fair[0], dec!(0.5));
+        assert_decimal_eq(fair[1], dec!(0.5));
+
+        let market = [
+            Odds::decimal(dec!(1.5)).unwrap(),
+            Odds::decimal(dec!(2.5)).unwrap(),
+        ];
+        let fair = remove_vig(&market).unwrap();
+
+        // 0.6667 and 0.4 normalized by 1.0667
+        assert_decimal_eq(fair[0], dec!(0.625));
+        assert_decimal_eq(fair[1], dec!(0.375));
+    }
+
+    #[test]
+    fn test_closing_line_value() {
+        // Taken 2.2, closed at evens
+        let clv = closing_line_value(
+            &Odds::decimal(dec!(2.2)).unwrap(),
+            &Odds::american(100).unwrap(),
+        )
+        .unwrap();
+        assert_decimal_eq(clv.percentage, dec!(0.1));
+        assert_decimal_eq(clv.implied_probability_difference, dec!(0.0455));
+
+        // Price drifted after taking it
+        let clv = closing_line_value(
+            &Odds::fractional(2, 1).unwrap(),
+            &Odds::fractional(3, 1).unwrap(),
+        )
+        .unwrap();
+        assert_decimal_eq(clv.percentage, dec!(-0.25));
+        assert_decimal_eq(clv.implied_probability_difference, dec!(-0.0833));
+    }
+
+    #[test]
+    fn test_closing_line_value_devigged() {
+        let closing = [Odds::american(-110).unwrap(), Odds::american(-110).unwrap()];
+
+        // Taken at evens, fair closing price is evens too
+        let clv = closing_line_value_devigged(&Odds::american(100).unwrap(), &closing, 0).unwrap();
+        assert_decimal_eq(clv.percentage, Decimal::ZERO);
+        assert_decimal_eq(clv.implied_probability_difference, Decimal::ZERO);
+
+        // Without removing margin it looks like beating the line
+        let clv = closing_line_value(&Odds::american(100).unwrap(), &closing[0]).unwrap();
+        assert_decimal_eq(clv.percentage, dec!(0.0476));
+
+        assert_eq!(
+            closing_line_value_devigged(&Odds::american(100).unwrap(), &closing, 2),
+            Err(CalculationError::InvalidSelection)
+        );
+    }
+}
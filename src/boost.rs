@@ -0,0 +1,242 @@
+use rust_decimal::Decimal;
+
+use crate::{
+    CalculationError, ConversionConfig, Fraction, Odds, decimal_to_fractional_custom,
+    lookup_funcs::lookup_fraction_at_or_below_ratio, odds::decimal_ratio,
+};
+
+/// Kind of a price boost offer.
+#[derive(Clone, Copy)]
+pub enum BoostKind {
+    /// Winnings increased by given fraction, f. ex. `0.25` for "25% profit boost".
+    ProfitPercentage(Decimal),
+    /// Price replaced with a better one, f. ex. "boosted from 2/1 to 3/1".
+    FixedPrice(Odds),
+}
+
+/// Boost offer applied to a price, with an optional cap on the extra winnings.
+#[derive(Clone, Copy)]
+pub struct Boost {
+    pub kind: BoostKind,
+    /// Maximum extra winnings paid on top of the unboosted price.
+    pub max_extra_winnings: Option<Decimal>,
+}
+
+impl Boost {
+    /// Create profit boost, f. ex. `0.25` for "25% profit boost".
+    pub fn profit_percentage(percentage: Decimal) -> Self {
+        Self {
+            kind: BoostKind::ProfitPercentage(percentage),
+            max_extra_winnings: None,
+        }
+    }
+
+    /// Create boost to a fixed price.
+    pub fn fixed_price(odds: Odds) -> Self {
+        Self {
+            kind: BoostKind::FixedPrice(odds),
+            max_extra_winnings: None,
+        }
+    }
+
+    /// Limit extra winnings paid by the boost. Negative cap makes the boost invalid.
+    pub fn max_extra_winnings(mut self, cap: Decimal) -> Self {
+        self.max_extra_winnings = Some(cap);
+        self
+    }
+
+    /// Apply boost to the price for given stake, returns decimal odds.
+    ///
+    /// Stake is used only to apply the winnings cap - if the cap is reached, the effective price is lowered.
+    pub fn apply(&self, odds: &Odds, stake: Decimal) -> Result<Odds, CalculationError> {
+        if stake < Decimal::ZERO {
+            return Err(CalculationError::NegativeStake);
+        }
+
+        let original = odds.exact_decimal();
+
+        let boosted = match self.kind {
+            BoostKind::ProfitPercentage(percentage) => {
+                if percentage < Decimal::ZERO {
+                    return Err(CalculationError::InvalidBoost);
+                }
+                Decimal::ONE + (original - Decimal::ONE) * (Decimal::ONE + percentage)
+            }
+            BoostKind::FixedPrice(price) => {
+                let price = price.exact_decimal();
+                if price < original {
+                    return Err(CalculationError::InvalidBoost);
+                }
+                price
+            }
+        };
+
+        let boosted = match self.max_extra_winnings {
+            Some(cap) if cap < Decimal::ZERO => return Err(CalculationError::InvalidBoost),
+            Some(cap) if stake > Decimal::ZERO && (boosted - original) * stake > cap => {
+                original + cap / stake
+            }
+            _ => boosted,
+        };
+
+        Ok(Odds::decimal(boosted)?)
+    }
+
+    /// Apply boost and convert the result to a traditional fraction from lookup tables.
+    ///
+    /// The fraction is snapped down, so the boosted price is never exceeded. If lookup is disabled or
+    /// no traditional fraction is found, regular conversion is used.
+    pub fn apply_fractional(
+        &self,
+        odds: &Odds,
+        stake: Decimal,
+        config: &ConversionConfig,
+    ) -> Result<Fraction, CalculationError> {
+        let boosted = self.apply(odds, stake)?.exact_decimal();
+
+        // Uncapped fixed price is snapped exactly, f. ex. 1/3 is not above its own decimal
+        let limit = match self.kind {
+            BoostKind::FixedPrice(price) if price.exact_decimal() == boosted => {
+                price.decimal_ratio()
+            }
+            // Not normalised, rounding the input up could exceed the boost
+            _ => decimal_ratio(boosted),
+        };
+
+        match lookup_fraction_at_or_below_ratio(limit, config) {
+            Some((num, den)) => Ok(Fraction::new_unchecked(num, den)),
+            None => Ok(decimal_to_fractional_custom(boosted, config)?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::RoundingStrategy;
+    use rust_decimal_macros::dec;
+
+    use crate::testing_helpers::{assert_decimal_eq, frac};
+
+    use super::*;
+
+    #[test]
+    fn test_profit_percentage() {
+        let boost = Boost::profit_percentage(dec!(0.25));
+
+        // 5/2 boosted by 25% is 25/8
+        let boosted = boost
+            .apply(&Odds::fractional(5, 2).unwrap(), dec!(10))
+            .unwrap();
+        assert_decimal_eq(boosted.to_decimal().unwrap(), dec!(4.125));
+        assert_eq!(boosted.to_american(), Ok(313));
+
+        // Snapped to the nearest traditional fraction below
+        assert_eq!(
+            boost.apply_fractional(
+                &Odds::fractional(5, 2).unwrap(),
+                dec!(10),
+                &ConversionConfig::default()
+            ),
+            Ok(frac(3, 1))
+        );
+
+        // Evens boosted to 5/4
+        assert_eq!(
+            boost.apply_fractional(
+                &Odds::american(100).unwrap(),
+                dec!(10),
+                &ConversionConfig::default()
+            ),
+            Ok(frac(5, 4))
+        );
+
+        // Without lookup the exact fraction is returned
+        assert_eq!(
+            boost.apply_fractional(
+                &Odds::fractional(5, 2).unwrap(),
+                dec!(10),
+                &ConversionConfig::default().no_lookup()
+            ),
+            Ok(frac(25, 8))
+        );
+    }
+
+    #[test]
+    fn test_fixed_price() {
+        let boost = Boost::fixed_price(Odds::fractional(3, 1).unwrap());
+
+        let boosted = boost
+            .apply(&Odds::decimal(dec!(3.0)).unwrap(), dec!(10))
+            .unwrap();
+        assert_decimal_eq(boosted.to_decimal().unwrap(), dec!(4.0));
+
+        assert_eq!(
+            boost
+                .apply(&Odds::decimal(dec!(5.0)).unwrap(), dec!(10))
+                .err(),
+            Some(CalculationError::InvalidBoost)
+        );
+    }
+
+    #[test]
+    fn test_max_extra_winnings() {
+        // 2/1 to 4/1 pays 20 extra for 10 stake, capped to 5
+        let boost = Boost::fixed_price(Odds::fractional(4, 1).unwrap()).max_extra_winnings(dec!(5));
+
+        let boosted = boost
+            .apply(&Odds::fractional(2, 1).unwrap(), dec!(10))
+            .unwrap();
+        assert_decimal_eq(boosted.to_decimal().unwrap(), dec!(3.5));
+
+        // Below the cap price is not affected
+        let boosted = boost
+            .apply(&Odds::fractional(2, 1).unwrap(), dec!(2))
+            .unwrap();
+        assert_decimal_eq(boosted.to_decimal().unwrap(), dec!(5.0));
+
+        assert_eq!(
+            boost.apply_fractional(
+                &Odds::fractional(2, 1).unwrap(),
+                dec!(10),
+                &ConversionConfig::default()
+            ),
+            Ok(frac(5, 2))
+        );
+
+        // Negative cap would lower the price
+        let boost = Boost::profit_percentage(dec!(0.1)).max_extra_winnings(dec!(-4));
+        assert_eq!(
+            boost
+                .apply(&Odds::decimal(dec!(3.0)).unwrap(), dec!(10))
+                .err(),
+            Some(CalculationError::InvalidBoost)
+        );
+    }
+
+    #[test]
+    fn test_apply_fractional_boundary() {
+        let config = ConversionConfig::default();
+
+        // 4/3 has no exact decimal, but the boosted price is 1/3 from the table
+        let boost = Boost::fixed_price(Odds::fractional(1, 3).unwrap());
+        assert_eq!(
+            boost.apply_fractional(&Odds::fractional(1, 4).unwrap(), dec!(10), &config),
+            Ok(frac(1, 3))
+        );
+
+        let boost = Boost::fixed_price(Odds::fractional(1, 2).unwrap());
+        assert_eq!(
+            boost.apply_fractional(&Odds::fractional(1, 4).unwrap(), dec!(10), &config),
+            Ok(frac(1, 2))
+        );
+
+        // 1.665 would be rounded up to 1.67 (4/6), which pays more than the boost
+        let rounded = config.round_decimal_input(2, RoundingStrategy::MidpointAwayFromZero);
+        let boost = Boost::profit_percentage(dec!(0.33));
+        let fraction = boost
+            .apply_fractional(&Odds::decimal(dec!(1.5)).unwrap(), dec!(10), &rounded)
+            .unwrap();
+        assert_eq!(fraction, frac(8, 13));
+        assert!(fraction.to_decimal() <= dec!(0.665));
+    }
+}
@@ -0,0 +1,170 @@
+use rust_decimal::Decimal;
+
+use crate::{CalculationError, Odds};
+
+/// State of a single selection of a placed bet.
+#[derive(Clone, Copy)]
+pub enum LegStatus {
+    /// Selection already won.
+    Won,
+    /// Selection already lost, the whole bet is lost.
+    Lost,
+    /// Selection not settled yet, with its current price.
+    Open(Odds),
+}
+
+/// Single selection of a placed bet.
+#[derive(Clone, Copy)]
+pub struct BetLeg {
+    /// Price taken when the bet was placed.
+    pub taken: Odds,
+    pub status: LegStatus,
+}
+
+/// Placed single or accumulator bet which can be cashed out.
+#[derive(Clone)]
+pub struct OpenBet {
+    pub stake: Decimal,
+    pub legs: Vec<BetLeg>,
+}
+
+impl OpenBet {
+    /// Creates a single bet.
+    pub fn single(stake: Decimal, taken: Odds, current: Odds) -> Self {
+        Self {
+            stake,
+            legs: vec![BetLeg {
+                taken,
+                status: LegStatus::Open(current),
+            }],
+        }
+    }
+
+    /// Full return of the bet if all legs win.
+    pub fn potential_return(&self) -> Result<Decimal, CalculationError> {
+        if self.stake < Decimal::ZERO {
+            return Err(CalculationError::NegativeStake);
+        }
+
+        Ok(self
+            .legs
+            .iter()
+            .fold(self.stake, |acc, leg| acc * leg.taken.exact_decimal()))
+    }
+
+    /// Fair cash-out value: potential return discounted by current prices of the open legs.
+    pub fn fair_cash_out_value(&self) -> Result<Decimal, CalculationError> {
+        let mut value = self.potential_return()?;
+
+        for leg in &self.legs {
+            match leg.status {
+                LegStatus::Won => (),
+                LegStatus::Lost => return Ok(Decimal::ZERO),
+                LegStatus::Open(current) => value /= current.exact_decimal(),
+            }
+        }
+
+        Ok(value)
+    }
+
+    /// Cash-out offer after taking a margin, f. ex. `0.05` keeps 5% of the fair value.
+    pub fn cash_out_offer(&self, margin: Decimal) -> Result<Decimal, CalculationError> {
+        if margin < Decimal::ZERO || margin >= Decimal::ONE {
+            return Err(CalculationError::InvalidMargin);
+        }
+
+        Ok(self.fair_cash_out_value()? * (Decimal::ONE - margin))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use crate::testing_helpers::assert_decimal_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_single() {
+        // Backed at 5/1, now 2/1
+        let bet = OpenBet::single(
+            dec!(10),
+            Odds::fractional(5, 1).unwrap(),
+            Odds::fractional(2, 1).unwrap(),
+        );
+
+        assert_decimal_eq(bet.potential_return().unwrap(), dec!(60));
+        assert_decimal_eq(bet.fair_cash_out_value().unwrap(), dec!(20));
+        assert_decimal_eq(bet.cash_out_offer(dec!(0.1)).unwrap(), dec!(18));
+
+        // Price drifted
+        let bet = OpenBet::single(
+            dec!(10),
+            Odds::decimal(dec!(3.0)).unwrap(),
+            Odds::american(500).unwrap(),
+        );
+        assert_decimal_eq(bet.fair_cash_out_value().unwrap(), dec!(5));
+    }
+
+    #[test]
+    fn test_accumulator() {
+        let bet = OpenBet {
+            stake: dec!(5),
+            legs: vec![
+                BetLeg {
+                    taken: Odds::decimal(dec!(2.0)).unwrap(),
+                    status: LegStatus::Won,
+                },
+                BetLeg {
+                    taken: Odds::fractional(6, 4).unwrap(),
+                    status: LegStatus::Won,
+                },
+                BetLeg {
+                    taken: Odds::american(300).unwrap(),
+                    status: LegStatus::Open(Odds::decimal(dec!(2.5)).unwrap()),
+                },
+            ],
+        };
+
+        // 5 * 2 * 2.5 * 4
+        assert_decimal_eq(bet.potential_return().unwrap(), dec!(100));
+        assert_decimal_eq(bet.fair_cash_out_value().unwrap(), dec!(40));
+        assert_decimal_eq(bet.cash_out_offer(dec!(0.05)).unwrap(), dec!(38));
+        assert_decimal_eq(bet.cash_out_offer(Decimal::ZERO).unwrap(), dec!(40));
+
+        // All legs won - cash-out is worth the full return
+        let mut settled = bet.clone();
+        settled.legs[2].status = LegStatus::Won;
+        assert_decimal_eq(settled.fair_cash_out_value().unwrap(), dec!(100));
+
+        // Any lost leg makes the bet worthless
+        let mut lost = bet.clone();
+        lost.legs[0].status = LegStatus::Lost;
+        assert_eq!(lost.fair_cash_out_value(), Ok(Decimal::ZERO));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let bet = OpenBet::single(
+            dec!(10),
+            Odds::decimal(dec!(3.0)).unwrap(),
+            Odds::decimal(dec!(2.0)).unwrap(),
+        );
+
+        assert_eq!(
+            bet.cash_out_offer(dec!(1.5)),
+            Err(CalculationError::InvalidMargin)
+        );
+
+        let bet = OpenBet::single(
+            dec!(-10),
+            Odds::decimal(dec!(3.0)).unwrap(),
+            Odds::decimal(dec!(2.0)).unwrap(),
+        );
+        assert_eq!(
+            bet.fair_cash_out_value(),
+            Err(CalculationError::NegativeStake)
+        );
+    }
+}
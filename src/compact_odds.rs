@@ -0,0 +1,83 @@
+//! Compact string representation of [`Odds`](crate::Odds): `"+150"`, `"-112.5"`, `"3.50"` or `"5/2"`.
+//!
+//! By default `Odds` are (de)serialized as a tagged enum, f. ex. `{"format":"american","value":150}`.
+//! Use this module with `#[serde(with = "oddsidizer::compact_odds")]` to store odds as strings instead.
+//! Strings are validated on deserialization.
+
+use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+use crate::Odds;
+
+pub fn serialize<S: Serializer>(odds: &Odds, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(odds)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Odds, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    s.parse()
+        .map_err(|err| D::Error::custom(format!("{err}: {s}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+    use serde::{Deserialize, Serialize};
+
+    use crate::Odds;
+
+    #[derive(Serialize, Deserialize)]
+    struct Runner {
+        #[serde(with = "crate::compact_odds")]
+        price: Odds,
+    }
+
+    #[test]
+    fn test_tagged() {
+        let json = serde_json::to_string(&Odds::american(150).unwrap()).unwrap();
+        assert_eq!(json, r#"{"format":"american","value":150}"#);
+
+        let json = serde_json::to_string(&Odds::fractional(5, 2).unwrap()).unwrap();
+        assert_eq!(json, r#"{"format":"fractional","value":{"num":5,"den":2}}"#);
+
+        let odds: Odds = serde_json::from_str(r#"{"format":"decimal","value":"3.50"}"#).unwrap();
+        assert_eq!(odds.to_string(), "3.50");
+
+        let json = serde_json::to_string(&Odds::precise_american(dec!(-112.5)).unwrap()).unwrap();
+        assert_eq!(json, r#"{"format":"precise_american","value":"-112.5"}"#);
+    }
+
+    #[test]
+    fn test_compact() {
+        for (odds, expected) in [
+            (Odds::american(150).unwrap(), r#"{"price":"+150"}"#),
+            (Odds::american(-110).unwrap(), r#"{"price":"-110"}"#),
+            (
+                Odds::precise_american(dec!(137.5)).unwrap(),
+                r#"{"price":"+137.5"}"#,
+            ),
+            (Odds::decimal(dec!(3.50)).unwrap(), r#"{"price":"3.50"}"#),
+            (Odds::fractional(5, 2).unwrap(), r#"{"price":"5/2"}"#),
+        ] {
+            let json = serde_json::to_string(&Runner { price: odds }).unwrap();
+            assert_eq!(json, expected);
+
+            let runner: Runner = serde_json::from_str(&json).unwrap();
+            assert_eq!(runner.price.to_string(), odds.to_string());
+        }
+    }
+
+    #[test]
+    fn test_compact_validation() {
+        for invalid in ["5/0", "0/1", "+0", "1.00", "0.5", "evens"] {
+            let json = format!(r#"{{"price":"{invalid}"}}"#);
+            assert!(
+                serde_json::from_str::<Runner>(&json).is_err(),
+                "{invalid} should be rejected"
+            );
+        }
+
+        // Unsigned integer is decimal odds, not american
+        let runner: Runner = serde_json::from_str(r#"{"price":"150"}"#).unwrap();
+        assert_eq!(runner.price.to_decimal(), Ok(dec!(150)));
+    }
+}
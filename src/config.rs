@@ -1,6 +1,12 @@
-use rust_decimal::RoundingStrategy;
+use std::{borrow::Cow, cmp::Ordering, sync::Arc};
+
+use rust_decimal::{Decimal, RoundingStrategy};
+use rust_decimal_macros::dec;
+
+use crate::LookupTable;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FractionStrategy {
     /// Plain method (less precise, but faster, f. ex. 1.33 gives 33/100 instead of 1/3).
     ///
@@ -8,9 +14,47 @@ pub enum FractionStrategy {
     Plain,
     /// Use a continued fraction algorithm for better precision and simple fractions. (1.33 gives 1/3 instead of 33/100)
     Simplify,
+    /// Pick the nearest fraction with one of the preferred denominators, f. ex. 3.7 gives 11/4 instead of 27/10
+    /// when 10 is not preferred. Direction of rounding is set by the price rounding.
+    PreferredDenominators,
 }
 
+/// Direction in which prices are rounded when they can't be represented exactly.
+///
+/// Honoured by all converters: results found in lookup tables are skipped if they are rounded the other way.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PriceRounding {
+    /// Nearest price in either direction, using `rounding_strategy` for computed values.
+    Nearest,
+    /// Shorter price (higher implied probability) - in the bookmaker's favour, never overpaying.
+    Shorten,
+    /// Longer price (lower implied probability) - in the punter's favour.
+    Lengthen,
+}
+
+impl PriceRounding {
+    /// Check if the converted price compared to the source one (`Less` if shorter) is rounded in allowed direction.
+    pub(crate) fn allows(self, converted: Ordering) -> bool {
+        match self {
+            PriceRounding::Nearest => true,
+            PriceRounding::Shorten => converted != Ordering::Greater,
+            PriceRounding::Lengthen => converted != Ordering::Less,
+        }
+    }
+
+    /// Rounding strategy for values growing with the price (decimal, fractional and american odds).
+    pub(crate) fn strategy(self, nearest: RoundingStrategy) -> RoundingStrategy {
+        match self {
+            PriceRounding::Nearest => nearest,
+            PriceRounding::Shorten => RoundingStrategy::ToNegativeInfinity,
+            PriceRounding::Lengthen => RoundingStrategy::ToPositiveInfinity,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LookupVariant {
     /// No lookup
     None,
@@ -18,10 +62,50 @@ pub enum LookupVariant {
     Basic,
     /// Extended lookup - covers more values but also gives more rounded results, f. ex. 1.0013 -> 1/750 instead of 1/768
     Extended,
+    /// Custom table (f. ex. price board of a brand) used instead of the built-in ones
+    Custom(Arc<LookupTable>),
+}
+
+/// When the continued fraction algorithm (simplify strategy) can stop refining the fraction.
+///
+/// The algorithm always stops when the denominator would exceed `max_denominator`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FractionTolerance {
+    /// Stop when the remainder of the expansion is below the value (capped at the fractional part of the odds).
+    ///
+    /// This is the default (`0.01`), giving roundish fractions for long prices (1.333 gives 1/3)
+    /// and precise ones for short prices (1.001 gives 1/1000).
+    Remainder(Decimal),
+    /// Stop when the fraction differs from the fractional part of the odds by at most the value.
+    Absolute(Decimal),
+    /// Stop when the decimal price of the fraction differs from the odds by at most the given part of the odds.
+    Relative(Decimal),
+    /// Stop when the implied probability of the fraction differs from the one of the odds by at most the value.
+    ImpliedProbability(Decimal),
+}
+
+/// How decimal odds are normalised before conversion and lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DecimalNormalization {
+    /// Only trailing zeros are removed, so 1.670 is converted as 1.67.
+    Normalize,
+    /// Rounded to given decimal places first, so 1.667 is converted as 1.67 with 2 places.
+    Round {
+        decimal_places: u32,
+        #[cfg_attr(feature = "serde", serde(with = "rounding_strategy_serde"))]
+        strategy: RoundingStrategy,
+    },
 }
 
 /// Configuration for conversion functions.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct ConversionConfig {
     /// Use lookup tables first for conversion, then fallback to regular computations
     /// Note: When using lookup tables feature, conversion from 1.67 or -150 gives 4/6 instead of 2/3 (see README.md)
@@ -29,7 +113,78 @@ pub struct ConversionConfig {
     /// Fractions computing strategy
     pub fraction_strategy: FractionStrategy,
     /// Rounding method for Decimal type
+    #[cfg_attr(feature = "serde", serde(with = "rounding_strategy_serde"))]
     pub rounding_strategy: RoundingStrategy,
+    /// Step american odds are rounded to, f. ex. `0.5` for -112.5 or `5` for -115.
+    /// Whole american odds (`i32`) are additionally rounded to whole numbers.
+    /// Not positive step disables rounding of precise american odds.
+    pub american_step: Decimal,
+    /// Maximum denominator produced by the simplify strategy, zero fails with [`ConversionError::ZeroMaxDenominator`](crate::ConversionError::ZeroMaxDenominator).
+    pub max_denominator: u32,
+    /// Precision of the simplify strategy.
+    pub fraction_tolerance: FractionTolerance,
+    /// Denominator the plain strategy starts with before reducing, f. ex. 1.33 gives 330/1000 reduced to 33/100.
+    /// Zero fails with [`ConversionError::ZeroPlainScale`](crate::ConversionError::ZeroPlainScale).
+    pub plain_scale: u32,
+    /// Denominators searched by the preferred denominators strategy, earlier ones win ties.
+    pub preferred_denominators: Cow<'static, [u32]>,
+    /// Direction of rounding of converted prices.
+    pub price_rounding: PriceRounding,
+    /// Normalisation of decimal odds passed to conversions from decimal and manual lookups.
+    pub decimal_normalization: DecimalNormalization,
+}
+
+/// Denominators commonly used by bookmakers for traditional fractions.
+pub const PREFERRED_DENOMINATORS: &[u32] =
+    &[1, 2, 4, 5, 8, 10, 11, 13, 15, 20, 25, 30, 40, 50, 100];
+
+/// `RoundingStrategy` doesn't implement serde traits, so it's (de)serialized by variant name.
+#[cfg(feature = "serde")]
+mod rounding_strategy_serde {
+    use rust_decimal::RoundingStrategy;
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    #[allow(deprecated)]
+    pub fn serialize<S: Serializer>(
+        strategy: &RoundingStrategy,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        // Deprecated variants are aliases of the current ones
+        let name = match strategy {
+            RoundingStrategy::MidpointNearestEven | RoundingStrategy::BankersRounding => {
+                "MidpointNearestEven"
+            }
+            RoundingStrategy::MidpointAwayFromZero | RoundingStrategy::RoundHalfUp => {
+                "MidpointAwayFromZero"
+            }
+            RoundingStrategy::MidpointTowardZero | RoundingStrategy::RoundHalfDown => {
+                "MidpointTowardZero"
+            }
+            RoundingStrategy::ToZero | RoundingStrategy::RoundDown => "ToZero",
+            RoundingStrategy::AwayFromZero | RoundingStrategy::RoundUp => "AwayFromZero",
+            RoundingStrategy::ToNegativeInfinity => "ToNegativeInfinity",
+            RoundingStrategy::ToPositiveInfinity => "ToPositiveInfinity",
+        };
+        serializer.serialize_str(name)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<RoundingStrategy, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        match name.as_str() {
+            "MidpointNearestEven" => Ok(RoundingStrategy::MidpointNearestEven),
+            "MidpointAwayFromZero" => Ok(RoundingStrategy::MidpointAwayFromZero),
+            "MidpointTowardZero" => Ok(RoundingStrategy::MidpointTowardZero),
+            "ToZero" => Ok(RoundingStrategy::ToZero),
+            "AwayFromZero" => Ok(RoundingStrategy::AwayFromZero),
+            "ToNegativeInfinity" => Ok(RoundingStrategy::ToNegativeInfinity),
+            "ToPositiveInfinity" => Ok(RoundingStrategy::ToPositiveInfinity),
+            other => Err(D::Error::custom(format!(
+                "unknown rounding strategy: {other}"
+            ))),
+        }
+    }
 }
 
 impl Default for ConversionConfig {
@@ -38,8 +193,24 @@ impl Default for ConversionConfig {
     /// - lookup enabled
     /// - fractions simplified
     /// - MidpointAwayFromZero (RoundHalfUp) rounding strategy
+    /// - american odds rounded to whole numbers
+    /// - denominators up to 1000 with remainder tolerance of 0.01 for simplified fractions
+    /// - scale of 1000 for plain fractions
+    /// - [`PREFERRED_DENOMINATORS`] for the preferred denominators strategy
+    /// - prices rounded to the nearest value
+    /// - decimal odds normalised without rounding
+    ///
+    /// Default functions (without `_custom`) use the default of [`ConfigRegistry::global`](crate::ConfigRegistry::global)
+    /// instead, if it's set.
     fn default() -> Self {
-        DEFAULT_CONVERSION_CONFIG
+        DEFAULT_CONVERSION_CONFIG.clone()
+    }
+}
+
+impl ConversionConfig {
+    /// Standard settings without cloning.
+    pub(crate) fn builtin_default() -> &'static Self {
+        &DEFAULT_CONVERSION_CONFIG
     }
 }
 
@@ -47,8 +218,50 @@ static DEFAULT_CONVERSION_CONFIG: ConversionConfig = ConversionConfig {
     lookup_tables_variant: LookupVariant::Basic,
     fraction_strategy: FractionStrategy::Simplify,
     rounding_strategy: RoundingStrategy::MidpointAwayFromZero, // former RoundHalfUp
+    american_step: Decimal::ONE,
+    max_denominator: 1000,
+    fraction_tolerance: FractionTolerance::Remainder(dec!(0.01)),
+    plain_scale: 1000,
+    preferred_denominators: Cow::Borrowed(PREFERRED_DENOMINATORS),
+    price_rounding: PriceRounding::Nearest,
+    decimal_normalization: DecimalNormalization::Normalize,
 };
 
+/// Presets for regional markets.
+impl ConversionConfig {
+    /// UK boards: traditional fractions from both lookup tables (4/6 rather than 2/3),
+    /// other prices rounded to the nearest fraction with one of [`PREFERRED_DENOMINATORS`].
+    pub fn uk_traditional() -> Self {
+        Self::default()
+            .extended_lookup()
+            .fraction_strategy(FractionStrategy::PreferredDenominators)
+    }
+
+    /// Irish boards use the same traditional fractions as UK ones.
+    pub fn irish_traditional() -> Self {
+        Self::uk_traditional()
+    }
+
+    /// US sportsbooks: no traditional fractions, moneylines in multiples of 5
+    /// and all prices rounded in the bookmaker's favour (f. ex. 1.95 gives -110 rather than -105).
+    pub fn us_sportsbook() -> Self {
+        Self::default()
+            .no_lookup()
+            .american_step(dec!(5))
+            .price_rounding(PriceRounding::Shorten)
+    }
+
+    /// Exchanges: conversions by formula without lookup tables, fractions within 0.0001
+    /// of the decimal price and precise american odds in cents.
+    pub fn exchange() -> Self {
+        Self::default()
+            .no_lookup()
+            .max_denominator(10000)
+            .fraction_tolerance(FractionTolerance::Absolute(dec!(0.0001)))
+            .american_step(dec!(0.01))
+    }
+}
+
 impl ConversionConfig {
     pub fn no_lookup(mut self) -> Self {
         self.lookup_tables_variant = LookupVariant::None;
@@ -60,6 +273,11 @@ impl ConversionConfig {
         self
     }
 
+    pub fn custom_lookup(mut self, table: impl Into<Arc<LookupTable>>) -> Self {
+        self.lookup_tables_variant = LookupVariant::Custom(table.into());
+        self
+    }
+
     pub fn plain_fraction_strategy(mut self) -> Self {
         self.fraction_strategy = FractionStrategy::Plain;
         self
@@ -74,4 +292,249 @@ impl ConversionConfig {
         self.rounding_strategy = strategy;
         self
     }
+
+    pub fn american_step(mut self, step: Decimal) -> Self {
+        self.american_step = step;
+        self
+    }
+
+    pub fn max_denominator(mut self, max_denominator: u32) -> Self {
+        self.max_denominator = max_denominator;
+        self
+    }
+
+    pub fn fraction_tolerance(mut self, tolerance: FractionTolerance) -> Self {
+        self.fraction_tolerance = tolerance;
+        self
+    }
+
+    pub fn plain_scale(mut self, scale: u32) -> Self {
+        self.plain_scale = scale;
+        self
+    }
+
+    pub fn preferred_denominators(mut self, denominators: impl Into<Cow<'static, [u32]>>) -> Self {
+        self.preferred_denominators = denominators.into();
+        self
+    }
+
+    pub fn price_rounding(mut self, rounding: PriceRounding) -> Self {
+        self.price_rounding = rounding;
+        self
+    }
+
+    pub fn decimal_normalization(mut self, normalization: DecimalNormalization) -> Self {
+        self.decimal_normalization = normalization;
+        self
+    }
+
+    /// Round decimal odds to given decimal places before conversion and lookup.
+    pub fn round_decimal_input(self, decimal_places: u32, strategy: RoundingStrategy) -> Self {
+        self.decimal_normalization(DecimalNormalization::Round {
+            decimal_places,
+            strategy,
+        })
+    }
+
+    /// Decimal odds as seen by conversions and lookups with this config.
+    pub fn normalize_decimal(&self, value: Decimal) -> Decimal {
+        match self.decimal_normalization {
+            DecimalNormalization::Normalize => value.normalize(),
+            DecimalNormalization::Round {
+                decimal_places,
+                strategy,
+            } => value
+                .round_dp_with_strategy(decimal_places, strategy)
+                .normalize(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Odds, american_to_decimal_custom, american_to_fractional_custom,
+        decimal_to_american_custom, decimal_to_fractional_custom,
+        decimal_to_precise_american_custom,
+        lookup_tables::{
+            get_american_to_fraction_map, get_decimal_to_fraction_extended_map,
+            get_decimal_to_fraction_map,
+        },
+    };
+
+    use super::*;
+
+    /// Decimal odds from both tables and a range of prices which are not in them.
+    fn decimals() -> impl Iterator<Item = Decimal> {
+        get_decimal_to_fraction_map()
+            .iter()
+            .chain(get_decimal_to_fraction_extended_map().iter())
+            .map(|(decimal, _)| decimal)
+            .chain((101..=5000).step_by(3).map(|value| Decimal::new(value, 2)))
+    }
+
+    #[test]
+    fn test_uk_traditional() {
+        let config = ConversionConfig::uk_traditional();
+        assert_eq!(
+            ConversionConfig::irish_traditional().lookup_tables_variant,
+            config.lookup_tables_variant
+        );
+
+        // Traditional fractions from the full tables
+        for (decimal, fraction) in get_decimal_to_fraction_map().iter() {
+            assert_eq!(
+                decimal_to_fractional_custom(decimal, &config).unwrap(),
+                *fraction
+            );
+        }
+        for (decimal, fraction) in get_decimal_to_fraction_extended_map().iter() {
+            if get_decimal_to_fraction_map().get(&decimal).is_none() {
+                assert_eq!(
+                    decimal_to_fractional_custom(decimal, &config).unwrap(),
+                    *fraction
+                );
+            }
+        }
+        for (american, fraction) in get_american_to_fraction_map().iter() {
+            assert_eq!(
+                american_to_fractional_custom(american, &config).unwrap(),
+                *fraction
+            );
+        }
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(1.67), &config),
+            Ok((4, 6).try_into().unwrap())
+        );
+
+        // Other prices have board denominators
+        for decimal in (101..=5000).step_by(3).map(|value| Decimal::new(value, 2)) {
+            if get_decimal_to_fraction_extended_map()
+                .get(&decimal)
+                .is_some()
+                || get_decimal_to_fraction_map().get(&decimal).is_some()
+            {
+                continue;
+            }
+            let fraction = decimal_to_fractional_custom(decimal, &config).unwrap();
+            assert!(
+                PREFERRED_DENOMINATORS
+                    .iter()
+                    .any(|den| den % fraction.den() == 0),
+                "{decimal} gives {fraction}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_us_sportsbook() {
+        let config = ConversionConfig::us_sportsbook();
+        assert_eq!(decimal_to_american_custom(dec!(1.95), &config), Ok(-110));
+        assert_eq!(
+            decimal_to_american_custom(dec!(1.95), &ConversionConfig::default()),
+            Ok(-105)
+        );
+        // Computed, not 4/6
+        assert_eq!(
+            american_to_fractional_custom(-150, &config),
+            Ok((2, 3).try_into().unwrap())
+        );
+
+        for decimal in decimals() {
+            let american = decimal_to_american_custom(decimal, &config).unwrap();
+            assert_eq!(american % 5, 0, "{decimal} gives {american}");
+            // Never longer than the source price
+            assert!(Odds::american(american).unwrap() >= Odds::decimal(decimal).unwrap());
+        }
+        for (american, _) in get_american_to_fraction_map().iter() {
+            let decimal = american_to_decimal_custom(american, &config).unwrap();
+            assert!(Odds::decimal(decimal).unwrap() >= Odds::american(american).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_exchange() {
+        let config = ConversionConfig::exchange();
+        let formula = ConversionConfig::default().no_lookup();
+
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(1.67), &config),
+            Ok((67, 100).try_into().unwrap())
+        );
+        assert_eq!(
+            decimal_to_precise_american_custom(dec!(1.91), &config),
+            Ok(dec!(-109.89))
+        );
+
+        for decimal in decimals() {
+            let fraction = decimal_to_fractional_custom(decimal, &config).unwrap();
+            assert!(
+                (fraction.to_decimal() + Decimal::ONE - decimal).abs() <= dec!(0.0001),
+                "{decimal} gives {fraction}"
+            );
+        }
+        for (american, _) in get_american_to_fraction_map().iter() {
+            assert_eq!(
+                american_to_decimal_custom(american, &config),
+                american_to_decimal_custom(american, &formula)
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() {
+        use rust_decimal::RoundingStrategy;
+
+        let config = ConversionConfig::default()
+            .extended_lookup()
+            .plain_fraction_strategy()
+            .rounding_strategy(RoundingStrategy::ToZero)
+            .american_step(Decimal::new(5, 1))
+            .max_denominator(100)
+            .fraction_tolerance(FractionTolerance::ImpliedProbability(Decimal::new(1, 3)));
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(
+            json,
+            r#"{"lookup_tables_variant":"Extended","fraction_strategy":"Plain","rounding_strategy":"ToZero","american_step":"0.5","max_denominator":100,"fraction_tolerance":{"ImpliedProbability":"0.001"},"plain_scale":1000,"preferred_denominators":[1,2,4,5,8,10,11,13,15,20,25,30,40,50,100],"price_rounding":"Nearest","decimal_normalization":"Normalize"}"#
+        );
+
+        let parsed: ConversionConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.lookup_tables_variant, LookupVariant::Extended);
+        assert_eq!(parsed.fraction_strategy, FractionStrategy::Plain);
+        assert_eq!(parsed.rounding_strategy, RoundingStrategy::ToZero);
+        assert_eq!(parsed.american_step, Decimal::new(5, 1));
+        assert_eq!(parsed.max_denominator, 100);
+        assert_eq!(
+            parsed.fraction_tolerance,
+            FractionTolerance::ImpliedProbability(Decimal::new(1, 3))
+        );
+
+        let config = ConversionConfig::default()
+            .preferred_denominators(vec![4, 8])
+            .price_rounding(PriceRounding::Shorten)
+            .round_decimal_input(2, RoundingStrategy::ToZero);
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(json.contains(
+            r#""decimal_normalization":{"Round":{"decimal_places":2,"strategy":"ToZero"}}"#
+        ));
+        let parsed: ConversionConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.preferred_denominators.as_ref(), &[4, 8]);
+        assert_eq!(parsed.price_rounding, PriceRounding::Shorten);
+        assert_eq!(parsed.decimal_normalization, config.decimal_normalization);
+
+        // Missing fields are taken from the default config
+        let parsed: ConversionConfig =
+            serde_json::from_str(r#"{"lookup_tables_variant":"None"}"#).unwrap();
+        assert_eq!(parsed.lookup_tables_variant, LookupVariant::None);
+        assert_eq!(parsed.american_step, Decimal::ONE);
+
+        assert!(
+            serde_json::from_str::<ConversionConfig>(
+                r#"{"lookup_tables_variant":"Basic","fraction_strategy":"Plain","rounding_strategy":"Sideways"}"#
+            )
+            .is_err()
+        );
+    }
 }
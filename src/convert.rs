@@ -1,18 +1,26 @@
-use rust_decimal::{Decimal, prelude::ToPrimitive};
-use rust_decimal_macros::dec;
+use std::{collections::HashMap, fmt, hash::Hash};
+
+use rust_decimal::{Decimal, RoundingStrategy, prelude::ToPrimitive};
 
 use crate::{
-    ConversionConfig, FractionStrategy, LookupVariant,
+    ConversionConfig, ConversionOutcome, Fraction, FractionStrategy, FractionTolerance,
+    LookupTable, LookupVariant, PriceRounding,
+    explain::{Method, SimplifyTrace},
     lookup_tables::{
-        get_american_to_decimal_extended_map, get_american_to_decimal_map,
+        StaticTable, TableKey, get_american_to_decimal_extended_map, get_american_to_decimal_map,
         get_american_to_fraction_extended_map, get_american_to_fraction_map,
+        get_decimal_to_american_extended_map, get_decimal_to_american_map,
         get_decimal_to_fraction_extended_map, get_decimal_to_fraction_map,
+        get_fraction_to_american_extended_map, get_fraction_to_american_map,
+        get_fraction_to_decimal_extended_map, get_fraction_to_decimal_map,
     },
+    odds::{american_ratio, cmp_ratios, decimal_ratio, fraction_ratio},
+    registry::with_default_config,
 };
 
 /// Convert from american to decimal using default parameters.
 pub fn american_to_decimal(value: i32) -> Result<Decimal, ConversionError> {
-    american_to_decimal_custom(value, &ConversionConfig::default())
+    with_default_config(|config| american_to_decimal_custom(value, config))
 }
 
 /// Convert from american to decimal using custom parameters.
@@ -20,80 +28,283 @@ pub fn american_to_decimal_custom(
     value: i32,
     config: &ConversionConfig,
 ) -> Result<Decimal, ConversionError> {
+    american_to_decimal_with_method(value, config).map(|(decimal, _)| decimal)
+}
+
+/// Convert from american to decimal using custom parameters, explaining how the result was produced.
+pub fn american_to_decimal_explained(
+    value: i32,
+    config: &ConversionConfig,
+) -> Result<ConversionOutcome<'_, Decimal>, ConversionError> {
+    american_to_decimal_with_method(value, config)
+        .map(|(decimal, method)| explain(decimal, method, config))
+}
+
+fn american_to_decimal_with_method(
+    value: i32,
+    config: &ConversionConfig,
+) -> Result<(Decimal, Method), ConversionError> {
     if value == 0 {
         return Err(ConversionError::AmericanZero);
     }
 
-    match config.lookup_tables_variant {
-        LookupVariant::Basic => {
-            if let Some(ret) = get_american_to_decimal_map().get(&value) {
-                return Ok(*ret);
-            }
-        }
-        LookupVariant::Extended => {
-            if let Some(ret) = get_american_to_decimal_map()
-                .get(&value)
-                .or(get_american_to_decimal_extended_map().get(&value))
-            {
-                return Ok(*ret);
-            }
-        }
-        _ => (),
+    let source = american_ratio(Decimal::from(value));
+    if let Some(hit) = lookup(
+        &value,
+        config,
+        get_american_to_decimal_map(),
+        get_american_to_decimal_extended_map(),
+        LookupTable::american_to_decimal_map,
+        |decimal| is_rounding_allowed(config, decimal_ratio(*decimal), source),
+    ) {
+        return Ok(hit);
     }
 
-    american_to_decimal_inner(value)
+    let decimal = american_to_decimal_inner(value, config)?;
+    Ok((decimal, Method::Formula))
+}
+
+/// Explain result of a conversion which doesn't use the simplify strategy.
+fn explain<T>(value: T, method: Method, config: &ConversionConfig) -> ConversionOutcome<'_, T> {
+    ConversionOutcome::new(value, method.provenance(SimplifyTrace::default()), config)
 }
 
-fn american_to_decimal_inner(value: i32) -> Result<Decimal, ConversionError> {
+/// Look the value up in the tables enabled in config, telling which table had it.
+///
+/// Values rounded in the direction not allowed by config are skipped.
+fn lookup<K: TableKey + Eq + Hash, V: Copy>(
+    key: &K,
+    config: &ConversionConfig,
+    basic: &StaticTable<K, V>,
+    extended: &StaticTable<K, V>,
+    custom: fn(&LookupTable) -> &HashMap<K, V>,
+    allowed: impl Fn(&V) -> bool,
+) -> Option<(V, Method)> {
+    let hit =
+        |value: Option<&V>, method: Method| value.filter(|v| allowed(v)).map(|v| (*v, method));
+    let basic_hit = || hit(basic.get(key), Method::BasicLookup);
+    let extended_hit = || hit(extended.get(key), Method::ExtendedLookup);
+
+    match &config.lookup_tables_variant {
+        LookupVariant::None => None,
+        LookupVariant::Basic => basic_hit(),
+        LookupVariant::Extended => basic_hit().or_else(extended_hit),
+        LookupVariant::Custom(table) => hit(custom(table).get(key), Method::CustomLookup),
+    }
+}
+
+fn american_to_decimal_inner(
+    value: i32,
+    config: &ConversionConfig,
+) -> Result<Decimal, ConversionError> {
     let value_dec = Decimal::from(value);
     if value > 0 {
         Ok(value_dec / Decimal::ONE_HUNDRED + Decimal::ONE)
     } else if value < 0 {
-        Ok(Decimal::ONE_HUNDRED / (-value_dec) + Decimal::ONE)
+        match config.price_rounding {
+            PriceRounding::Nearest => Ok(Decimal::ONE_HUNDRED / (-value_dec) + Decimal::ONE),
+            rounding => ratio_to_decimal(american_ratio(value_dec), rounding)
+                .ok_or(ConversionError::InvalidAmerican(value)),
+        }
     } else {
         Err(ConversionError::AmericanZero)
     }
 }
 
-// Convert from fractional to decimal (doesn't use conversion parameters).
+/// Check if the converted price is rounded in the direction allowed by config, comparing exact `(num, den)` prices.
+pub(crate) fn is_rounding_allowed(
+    config: &ConversionConfig,
+    converted: (u128, u128),
+    source: (u128, u128),
+) -> bool {
+    config.price_rounding.allows(cmp_ratios(converted, source))
+}
+
+/// Rounding strategy for computed values, directed by price rounding from config.
+fn rounding_strategy(config: &ConversionConfig) -> RoundingStrategy {
+    config.price_rounding.strategy(config.rounding_strategy)
+}
+
+/// Exact positive `num / den` as decimal price rounded in given direction at the finest possible scale.
+///
+/// `Decimal` division rounds to the nearest value, which could slightly lengthen the price.
+fn ratio_to_decimal((num, den): (u128, u128), rounding: PriceRounding) -> Option<Decimal> {
+    if den == 0 {
+        return None;
+    }
+
+    for scale in (0..=28).rev() {
+        let Some(scaled) = 10u128.pow(scale).checked_mul(num) else {
+            continue;
+        };
+        let quotient = match rounding {
+            PriceRounding::Lengthen => scaled.div_ceil(den),
+            _ => scaled / den,
+        };
+        if let Ok(quotient) = i128::try_from(quotient)
+            && let Ok(value) = Decimal::try_from_i128_with_scale(quotient, scale)
+        {
+            return Some(value.normalize());
+        }
+    }
+
+    None
+}
+
+/// Precise american odds of the exact decimal price `num / den` (above 1), rounded in given direction.
+fn ratio_to_american((num, den): (u128, u128), rounding: PriceRounding) -> Option<Decimal> {
+    if num >= 2 * den {
+        ratio_to_decimal(((num - den).checked_mul(100)?, den), rounding)
+    } else {
+        // Shorter prices have bigger negative odds, so the value is rounded the other way
+        let rounding = match rounding {
+            PriceRounding::Shorten => PriceRounding::Lengthen,
+            PriceRounding::Lengthen => PriceRounding::Shorten,
+            PriceRounding::Nearest => PriceRounding::Nearest,
+        };
+        ratio_to_decimal((den.checked_mul(100)?, num - den), rounding).map(|value| -value)
+    }
+}
+
+/// Convert from fractional to decimal using default parameters.
 pub fn fractional_to_decimal(num: u32, den: u32) -> Result<Decimal, ConversionError> {
+    with_default_config(|config| fractional_to_decimal_custom(num, den, config))
+}
+
+/// Convert from fractional to decimal using custom parameters.
+///
+/// Fractions are looked up as written, so 4/6 gives 1.67 while 2/3 is computed.
+/// Computed values are not rounded unless `price_rounding` requires it.
+pub fn fractional_to_decimal_custom(
+    num: u32,
+    den: u32,
+    config: &ConversionConfig,
+) -> Result<Decimal, ConversionError> {
+    fractional_to_decimal_with_method(num, den, config).map(|(decimal, _)| decimal)
+}
+
+/// Convert from fractional to decimal using custom parameters, explaining how the result was produced.
+pub fn fractional_to_decimal_explained(
+    num: u32,
+    den: u32,
+    config: &ConversionConfig,
+) -> Result<ConversionOutcome<'_, Decimal>, ConversionError> {
+    fractional_to_decimal_with_method(num, den, config)
+        .map(|(decimal, method)| explain(decimal, method, config))
+}
+
+fn fractional_to_decimal_with_method(
+    num: u32,
+    den: u32,
+    config: &ConversionConfig,
+) -> Result<(Decimal, Method), ConversionError> {
+    let decimal = fractional_to_exact_decimal(num, den)?;
+
+    let source = fraction_ratio(num, den);
+    if let Some(hit) = lookup(
+        &(num, den),
+        config,
+        get_fraction_to_decimal_map(),
+        get_fraction_to_decimal_extended_map(),
+        LookupTable::fraction_to_decimal_map,
+        |decimal| is_rounding_allowed(config, decimal_ratio(*decimal), source),
+    ) {
+        return Ok(hit);
+    }
+
+    let decimal = match config.price_rounding {
+        PriceRounding::Nearest => decimal,
+        rounding => {
+            ratio_to_decimal(source, rounding).ok_or(ConversionError::FractionOverflow(decimal))?
+        }
+    };
+    Ok((decimal, Method::Formula))
+}
+
+/// Convert from fractional to decimal with the formula only.
+fn fractional_to_exact_decimal(num: u32, den: u32) -> Result<Decimal, ConversionError> {
     if den == 0 {
-        Err(ConversionError::DenominatorZero)
+        Err(ConversionError::DenominatorZero { num })
     } else {
         Ok(Decimal::from(num) / Decimal::from(den) + Decimal::ONE)
     }
 }
 
 // Convert from decimal to fractional using default parameters.
-pub fn decimal_to_fractional(value: Decimal) -> Result<(u32, u32), ConversionError> {
-    decimal_to_fractional_custom(value, &ConversionConfig::default())
+pub fn decimal_to_fractional(value: Decimal) -> Result<Fraction, ConversionError> {
+    with_default_config(|config| decimal_to_fractional_custom(value, config))
 }
 
 // Convert from decimal to fractional using custom parameters.
 pub fn decimal_to_fractional_custom(
     value: Decimal,
     config: &ConversionConfig,
-) -> Result<(u32, u32), ConversionError> {
-    match config.lookup_tables_variant {
-        LookupVariant::Basic => {
-            if let Some(ret) = get_decimal_to_fraction_map().get(&value) {
-                return Ok(*ret);
-            }
-        }
-        LookupVariant::Extended => {
-            if let Some(ret) = get_decimal_to_fraction_map()
-                .get(&value)
-                .or(get_decimal_to_fraction_extended_map().get(&value))
-            {
-                return Ok(*ret);
-            }
-        }
-        _ => (),
+) -> Result<Fraction, ConversionError> {
+    decimal_to_fractional_with_method(value, config, None).map(|(fraction, _)| fraction)
+}
+
+/// Convert from decimal to fractional using custom parameters, explaining how the result was produced.
+///
+/// The value is normalised with `decimal_normalization` from the config first.
+pub fn decimal_to_fractional_explained(
+    value: Decimal,
+    config: &ConversionConfig,
+) -> Result<ConversionOutcome<'_, Fraction>, ConversionError> {
+    let mut trace = SimplifyTrace::default();
+    let (fraction, method) = decimal_to_fractional_with_method(value, config, Some(&mut trace))?;
+    Ok(ConversionOutcome::new(
+        fraction,
+        method.provenance(trace),
+        config,
+    ))
+}
+
+fn decimal_to_fractional_with_method(
+    value: Decimal,
+    config: &ConversionConfig,
+    trace: Option<&mut SimplifyTrace>,
+) -> Result<(Fraction, Method), ConversionError> {
+    let normalized = config.normalize_decimal(value);
+    decimal_to_fractional_towards(normalized, decimal_ratio(normalized), config, trace)
+        .map_err(|err| err.with_decimal_input(value))
+}
+
+/// Convert from decimal to fractional, rounding in the direction from config relative to the exact `source` price.
+///
+/// Source differs from the decimal value when it was converted from american odds.
+/// Convergents of the simplify strategy are collected only if `trace` is given.
+fn decimal_to_fractional_towards(
+    value: Decimal,
+    source: (u128, u128),
+    config: &ConversionConfig,
+    trace: Option<&mut SimplifyTrace>,
+) -> Result<(Fraction, Method), ConversionError> {
+    if let Some(((num, den), method)) = lookup(
+        &value,
+        config,
+        get_decimal_to_fraction_map(),
+        get_decimal_to_fraction_extended_map(),
+        LookupTable::decimal_to_fraction_map,
+        |(num, den)| is_rounding_allowed(config, fraction_ratio(*num, *den), source),
+    ) {
+        return Ok((Fraction::new_unchecked(num, den), method));
     }
 
     match config.fraction_strategy {
-        FractionStrategy::Plain => decimal_to_fractional_plain(value, config),
-        FractionStrategy::Simplify => decimal_to_fractional_simplify(value),
+        FractionStrategy::Plain => {
+            let fraction = decimal_to_fractional_plain(value, config)?;
+            let fraction = round_fraction_towards(value, fraction, source, config)?.reduced();
+            Ok((fraction, Method::Plain))
+        }
+        FractionStrategy::Simplify => {
+            let fraction = simplify(value, source, config, trace)?;
+            Ok((fraction, Method::Simplify))
+        }
+        FractionStrategy::PreferredDenominators => {
+            let fraction = decimal_to_fractional_preferred(value, config)?;
+            let fraction = round_fraction_towards(value, fraction, source, config)?;
+            Ok((fraction, Method::PreferredDenominators))
+        }
     }
 }
 
@@ -103,52 +314,176 @@ pub fn decimal_to_fractional_custom(
 pub fn decimal_to_fractional_plain(
     value: Decimal,
     config: &ConversionConfig,
-) -> Result<(u32, u32), ConversionError> {
+) -> Result<Fraction, ConversionError> {
     if value <= Decimal::ONE {
-        return Err(ConversionError::InvalidDecimal);
+        return Err(ConversionError::InvalidDecimal(value));
+    }
+    if config.plain_scale == 0 {
+        return Err(ConversionError::ZeroPlainScale);
     }
 
-    let numerator = (value - Decimal::ONE) * Decimal::ONE_THOUSAND;
+    let scale = u64::from(config.plain_scale);
+    let numerator = (value - Decimal::ONE) * Decimal::from(scale);
     let numerator = numerator
-        .round_dp_with_strategy(0, config.rounding_strategy)
+        .round_dp_with_strategy(0, rounding_strategy(config))
         .to_u64()
-        .unwrap_or_default();
+        .ok_or(ConversionError::FractionOverflow(value))?;
 
-    let divisor: u64 = num_integer::gcd(numerator, 100000);
+    if numerator == 0 {
+        return Err(ConversionError::FractionUnderflow(value));
+    }
 
-    let num = Decimal::from(numerator) / Decimal::from(divisor);
-    let den = Decimal::ONE_THOUSAND / Decimal::from(divisor);
+    // Divisor has to divide the scale, so the denominator stays whole
+    let divisor: u64 = num_integer::gcd(numerator, scale);
 
-    Ok((
-        num.to_u32().unwrap_or_default(),
-        den.to_u32().unwrap_or_default(),
-    ))
+    let num =
+        u32::try_from(numerator / divisor).map_err(|_| ConversionError::FractionOverflow(value))?;
+    let den = (scale / divisor) as u32;
+
+    Ok(Fraction::new_unchecked(num, den))
+}
+
+/// Convert from decimal to the nearest fraction with one of the preferred denominators from config,
+/// rounded in the direction set by the price rounding. The fraction is kept as on the board, so 3.125 gives 85/40
+/// when 40 is preferred and 8 is not. Equally close fractions go to the earlier denominator.
+///
+/// Bypasses look tables.
+pub fn decimal_to_fractional_preferred(
+    value: Decimal,
+    config: &ConversionConfig,
+) -> Result<Fraction, ConversionError> {
+    if value <= Decimal::ONE {
+        return Err(ConversionError::InvalidDecimal(value));
+    }
+
+    let fractional_part = value - Decimal::ONE;
+    // Best fraction with its numerator distance from the exact value (distance of values scaled by denominator)
+    let mut best: Option<(Fraction, Decimal)> = None;
+
+    for &den in config
+        .preferred_denominators
+        .iter()
+        .filter(|den| **den != 0)
+    {
+        let Some(exact) = fractional_part.checked_mul(Decimal::from(den)) else {
+            continue;
+        };
+        let candidates = match config.price_rounding {
+            PriceRounding::Nearest => [exact.floor(), exact.ceil()],
+            PriceRounding::Shorten => [exact.floor(); 2],
+            PriceRounding::Lengthen => [exact.ceil(); 2],
+        };
+
+        for num in candidates {
+            let Some(num) = num.to_u32().filter(|num| *num > 0) else {
+                continue;
+            };
+            let distance = (Decimal::from(num) - exact).abs();
+            // Compare distances of values exactly, ties are won by earlier denominators
+            let closer = best.is_none_or(|(fraction, best_distance)| {
+                distance * Decimal::from(fraction.den()) < best_distance * Decimal::from(den)
+            });
+            if closer {
+                best = Some((Fraction::new_unchecked(num, den), distance));
+            }
+        }
+    }
+
+    match best {
+        Some((fraction, _)) => Ok(fraction),
+        None if fractional_part < Decimal::ONE => Err(ConversionError::FractionUnderflow(value)),
+        None => Err(ConversionError::FractionOverflow(value)),
+    }
 }
 
 /// Conversion from decimal to fractional using a continued fraction algorithm to find the best rational approximation.
 ///
 /// This usually produce simplified fractions. Bypasses look tables.
-pub fn decimal_to_fractional_simplify(value: Decimal) -> Result<(u32, u32), ConversionError> {
+pub fn decimal_to_fractional_simplify(value: Decimal) -> Result<Fraction, ConversionError> {
+    with_default_config(|config| decimal_to_fractional_simplify_custom(value, config))
+}
+
+/// Conversion from decimal to fractional using a continued fraction algorithm, with max denominator
+/// and tolerance from config. Bypasses look tables.
+pub fn decimal_to_fractional_simplify_custom(
+    value: Decimal,
+    config: &ConversionConfig,
+) -> Result<Fraction, ConversionError> {
+    simplify(value, decimal_ratio(value), config, None)
+}
+
+/// Replace fraction rounded in the direction not allowed by config with the closest one
+/// with the same denominator on the allowed side.
+///
+/// Needed when the fraction was computed from decimal odds only approximating the source price.
+fn round_fraction_towards(
+    value: Decimal,
+    fraction: Fraction,
+    source: (u128, u128),
+    config: &ConversionConfig,
+) -> Result<Fraction, ConversionError> {
+    if is_rounding_allowed(
+        config,
+        fraction_ratio(fraction.num(), fraction.den()),
+        source,
+    ) {
+        return Ok(fraction);
+    }
+
+    // Fractional part of the source price is (num - den) / den
+    let (num, den) = source;
+    let scaled = num
+        .checked_sub(den)
+        .and_then(|part| part.checked_mul(u128::from(fraction.den())))
+        .ok_or(ConversionError::FractionOverflow(value))?;
+    let num = match config.price_rounding {
+        PriceRounding::Lengthen => scaled.div_ceil(den),
+        _ => scaled / den,
+    };
+
+    match u32::try_from(num) {
+        Ok(0) => Err(ConversionError::FractionUnderflow(value)),
+        Ok(num) => Ok(Fraction::new_unchecked(num, fraction.den())),
+        Err(_) => Err(ConversionError::FractionOverflow(value)),
+    }
+}
+
+/// Continued fraction algorithm, recording computed convergents and the final error in `trace` if given.
+///
+/// The result is rounded in the direction from config relative to the exact `source` price.
+fn simplify(
+    value: Decimal,
+    source: (u128, u128),
+    config: &ConversionConfig,
+    mut trace: Option<&mut SimplifyTrace>,
+) -> Result<Fraction, ConversionError> {
     if value <= Decimal::ONE {
-        return Err(ConversionError::InvalidDecimal);
+        return Err(ConversionError::InvalidDecimal(value));
+    }
+    if config.max_denominator == 0 {
+        return Err(ConversionError::ZeroMaxDenominator);
     }
 
     let fractional_part = value - Decimal::ONE;
 
-    // Set a practical limit for denominators in betting odds.
-    const MAX_DENOMINATOR: u64 = 1000;
-
-    // Epsilon for comparing decimals to handle precision errors from division.
-    // Note that the epsilon is dependent on the value itself - the bigger the
-    // value is, the bigger the epsilon can be to be more roundish-like,
-    // 1.333 -> 1/3 (bigger epsilon)
-    // 1.001 -> 1/1000 (smaller epsilon)
-    let epsilon = (value - Decimal::ONE).min(dec!(0.01));
+    // Practical limit for denominators in betting odds.
+    let max_denominator = u64::from(config.max_denominator);
 
     // Standard algorithm to find best rational approximation.
     let mut a = fractional_part;
     let (mut num, mut den) = (1u64, 0u64);
     let (mut num_prev, mut den_prev) = (0u64, 1u64);
+    let mut record = |num: u64, den: u64| {
+        if let Some(trace) = trace.as_deref_mut()
+            && let (Ok(num), Ok(den)) = (u32::try_from(num), u32::try_from(den))
+        {
+            trace.convergents.push(Fraction::new_unchecked(num, den));
+        }
+    };
+    let allowed = |num: u64, den: u64| {
+        let (num, den) = (u128::from(num), u128::from(den));
+        is_rounding_allowed(config, (num + den, den), source)
+    };
 
     loop {
         let a_floor = a.floor();
@@ -162,7 +497,7 @@ pub fn decimal_to_fractional_simplify(value: Decimal) -> Result<(u32, u32), Conv
         let den_next = whole.saturating_mul(den).saturating_add(den_prev);
 
         // Safety break for very large or complex decimals.
-        if den_next > MAX_DENOMINATOR {
+        if den_next > max_denominator {
             break;
         }
 
@@ -170,10 +505,13 @@ pub fn decimal_to_fractional_simplify(value: Decimal) -> Result<(u32, u32), Conv
         den_prev = den;
         num = num_next;
         den = den_next;
+        record(num, den);
 
         let remainder = a - a_floor;
-        // If remainder is negligible, we've found our fraction.
-        if remainder < epsilon {
+        // If remainder is negligible or the fraction is close enough, we've found our fraction.
+        if remainder.is_zero()
+            || (is_within_tolerance(value, num, den, remainder, config) && allowed(num, den))
+        {
             break;
         }
 
@@ -187,69 +525,307 @@ pub fn decimal_to_fractional_simplify(value: Decimal) -> Result<(u32, u32), Conv
         den = 1;
     }
 
-    Ok((num as u32, den as u32))
+    if !allowed(num, den) {
+        // Convergents alternate around the value, so semiconvergents between the previous convergent
+        // and the last one start on the allowed side - take the closest one which is still there.
+        let semiconvergent = |j: u64| {
+            (
+                num.saturating_mul(j).saturating_add(num_prev),
+                den.saturating_mul(j).saturating_add(den_prev),
+            )
+        };
+        let (mut low, mut high) = (0, max_denominator.saturating_sub(den_prev) / den);
+        while low < high {
+            let mid = low + (high - low).div_ceil(2);
+            let (num, den) = semiconvergent(mid);
+            if allowed(num, den) {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+
+        (num, den) = semiconvergent(low);
+        if den == 0 {
+            return Err(ConversionError::FractionOverflow(value));
+        }
+        record(num, den);
+    }
+
+    if num == 0 {
+        return Err(ConversionError::FractionUnderflow(value));
+    }
+
+    let (Ok(num), Ok(den)) = (u32::try_from(num), u32::try_from(den)) else {
+        return Err(ConversionError::FractionOverflow(value));
+    };
+    let fraction = Fraction::new_unchecked(num, den);
+    if let Some(trace) = trace {
+        trace.error = (fraction.to_decimal() - fractional_part).abs();
+    }
+    Ok(fraction)
+}
+
+fn is_within_tolerance(
+    value: Decimal,
+    num: u64,
+    den: u64,
+    remainder: Decimal,
+    config: &ConversionConfig,
+) -> bool {
+    let fractional_part = value - Decimal::ONE;
+    let approximation = || Decimal::from(num) / Decimal::from(den);
+
+    match config.fraction_tolerance {
+        // Epsilon for comparing decimals to handle precision errors from division.
+        // Note that the epsilon is dependent on the value itself - the bigger the
+        // value is, the bigger the epsilon can be to be more roundish-like,
+        // 1.333 -> 1/3 (bigger epsilon)
+        // 1.001 -> 1/1000 (smaller epsilon)
+        FractionTolerance::Remainder(epsilon) => remainder < fractional_part.min(epsilon),
+        // Zero is never close enough, the odds would be lost
+        _ if num == 0 => false,
+        FractionTolerance::Absolute(tolerance) => {
+            (approximation() - fractional_part).abs() <= tolerance
+        }
+        FractionTolerance::Relative(tolerance) => {
+            (approximation() - fractional_part).abs() <= tolerance * value
+        }
+        FractionTolerance::ImpliedProbability(tolerance) => {
+            let price = approximation() + Decimal::ONE;
+            (Decimal::ONE / price - Decimal::ONE / value).abs() <= tolerance
+        }
+    }
 }
 
 /// Convert from american to fractional with default parameters.
-pub fn american_to_fractional(value: i32) -> Result<(u32, u32), ConversionError> {
-    american_to_fractional_custom(value, &ConversionConfig::default())
+pub fn american_to_fractional(value: i32) -> Result<Fraction, ConversionError> {
+    with_default_config(|config| american_to_fractional_custom(value, config))
 }
 
 /// Convert from american to fractional with custom parameters.
 pub fn american_to_fractional_custom(
     value: i32,
     config: &ConversionConfig,
-) -> Result<(u32, u32), ConversionError> {
-    match config.lookup_tables_variant {
-        LookupVariant::Basic => {
-            if let Some(ret) = get_american_to_fraction_map().get(&value) {
-                return Ok(*ret);
-            }
-        }
-        LookupVariant::Extended => {
-            if let Some(ret) = get_american_to_fraction_map()
-                .get(&value)
-                .or(get_american_to_fraction_extended_map().get(&value))
-            {
-                return Ok(*ret);
-            }
-        }
-        _ => (),
+) -> Result<Fraction, ConversionError> {
+    american_to_fractional_with_method(value, config, None).map(|(fraction, _)| fraction)
+}
+
+/// Convert from american to fractional with custom parameters, explaining how the result was produced.
+///
+/// If the value is not found in american lookup tables, it's converted to decimal and then to fractional.
+pub fn american_to_fractional_explained(
+    value: i32,
+    config: &ConversionConfig,
+) -> Result<ConversionOutcome<'_, Fraction>, ConversionError> {
+    let mut trace = SimplifyTrace::default();
+    let (fraction, method) = american_to_fractional_with_method(value, config, Some(&mut trace))?;
+    Ok(ConversionOutcome::new(
+        fraction,
+        method.provenance(trace),
+        config,
+    ))
+}
+
+fn american_to_fractional_with_method(
+    value: i32,
+    config: &ConversionConfig,
+    trace: Option<&mut SimplifyTrace>,
+) -> Result<(Fraction, Method), ConversionError> {
+    let source = american_ratio(Decimal::from(value));
+    if let Some(((num, den), method)) = lookup(
+        &value,
+        config,
+        get_american_to_fraction_map(),
+        get_american_to_fraction_extended_map(),
+        LookupTable::american_to_fraction_map,
+        |(num, den)| is_rounding_allowed(config, fraction_ratio(*num, *den), source),
+    ) {
+        return Ok((Fraction::new_unchecked(num, den), method));
     }
 
-    let decimal = american_to_decimal_inner(value)?;
-    decimal_to_fractional(decimal)
+    let decimal = american_to_decimal_inner(value, config)?;
+    decimal_to_fractional_towards(decimal, source, config, trace)
+}
+
+/// Convert from not whole precise american to fractional, rounding relative to the exact american price.
+pub(crate) fn precise_american_to_fractional_custom(
+    value: Decimal,
+    config: &ConversionConfig,
+) -> Result<Fraction, ConversionError> {
+    let decimal = precise_american_to_decimal_custom(value, config)?;
+    decimal_to_fractional_towards(decimal, american_ratio(value), config, None)
+        .map(|(fraction, _)| fraction)
 }
 
 /// Convert from decimal to american with default parameters.
 pub fn decimal_to_american(decimal: Decimal) -> Result<i32, ConversionError> {
-    decimal_to_american_custom(decimal, &ConversionConfig::default())
+    with_default_config(|config| decimal_to_american_custom(decimal, config))
 }
 
 /// Convert from decimal to american with custom parameters.
+///
+/// Result is rounded to `american_step` from the config and then to a whole number.
 pub fn decimal_to_american_custom(
     decimal: Decimal,
     config: &ConversionConfig,
 ) -> Result<i32, ConversionError> {
-    if decimal >= Decimal::TWO {
-        ((decimal - Decimal::ONE) * Decimal::ONE_HUNDRED)
-            .round_dp_with_strategy(0, config.rounding_strategy)
-            .to_i32()
-            .ok_or(ConversionError::DecimalOverflow)
-            .map(normalize_american_odds)
-    } else if decimal > Decimal::ONE {
-        (-Decimal::ONE_HUNDRED / (decimal - Decimal::ONE))
-            .round_dp_with_strategy(0, config.rounding_strategy)
-            .to_i32()
-            .ok_or(ConversionError::DecimalOverflow)
+    decimal_to_american_with_method(decimal, config).map(|(american, _)| american)
+}
+
+/// Convert from decimal to american with custom parameters, explaining how the result was produced.
+///
+/// The value is normalised with `decimal_normalization` from the config first.
+pub fn decimal_to_american_explained(
+    decimal: Decimal,
+    config: &ConversionConfig,
+) -> Result<ConversionOutcome<'_, i32>, ConversionError> {
+    decimal_to_american_with_method(decimal, config)
+        .map(|(american, method)| explain(american, method, config))
+}
+
+fn decimal_to_american_with_method(
+    decimal: Decimal,
+    config: &ConversionConfig,
+) -> Result<(i32, Method), ConversionError> {
+    let input = decimal;
+    let decimal = config.normalize_decimal(decimal);
+    let source = decimal_ratio(decimal);
+    if decimal > Decimal::ONE
+        && let Some(hit) = lookup(
+            &decimal,
+            config,
+            get_decimal_to_american_map(),
+            get_decimal_to_american_extended_map(),
+            LookupTable::decimal_to_american_map,
+            |american| {
+                is_rounding_allowed(config, american_ratio(Decimal::from(*american)), source)
+            },
+        )
+    {
+        return Ok(hit);
+    }
+
+    let american = normalized_decimal_to_precise_american(decimal, config)
+        .map_err(|err| err.with_decimal_input(input))?
+        .round_dp_with_strategy(0, rounding_strategy(config))
+        .to_i32()
+        .ok_or(ConversionError::DecimalOverflow(input))?;
+
+    Ok((american, Method::Formula))
+}
+
+/// Convert from decimal to precise (decimal-valued) american with default parameters.
+pub fn decimal_to_precise_american(decimal: Decimal) -> Result<Decimal, ConversionError> {
+    with_default_config(|config| decimal_to_precise_american_custom(decimal, config))
+}
+
+/// Convert from decimal to precise (decimal-valued) american with custom parameters.
+///
+/// Result is rounded to `american_step` from the config, f. ex. 1.88 gives -113.5 with step 0.5.
+/// The value is normalised with `decimal_normalization` from the config first.
+pub fn decimal_to_precise_american_custom(
+    decimal: Decimal,
+    config: &ConversionConfig,
+) -> Result<Decimal, ConversionError> {
+    normalized_decimal_to_precise_american(config.normalize_decimal(decimal), config)
+        .map_err(|err| err.with_decimal_input(decimal))
+}
+
+/// Convert from decimal already normalised with the config to precise american.
+fn normalized_decimal_to_precise_american(
+    decimal: Decimal,
+    config: &ConversionConfig,
+) -> Result<Decimal, ConversionError> {
+    let american = if decimal <= Decimal::ONE {
+        return Err(ConversionError::InvalidDecimal(decimal));
+    } else if config.price_rounding != PriceRounding::Nearest {
+        ratio_to_american(decimal_ratio(decimal), config.price_rounding)
+            .ok_or(ConversionError::DecimalOverflow(decimal))?
+    } else if decimal >= Decimal::TWO {
+        (decimal - Decimal::ONE)
+            .checked_mul(Decimal::ONE_HUNDRED)
+            .ok_or(ConversionError::DecimalOverflow(decimal))?
+    } else {
+        (-Decimal::ONE_HUNDRED)
+            .checked_div(decimal - Decimal::ONE)
+            .ok_or(ConversionError::DecimalOverflow(decimal))?
+    };
+
+    Ok(round_american_to_step(american, config))
+}
+
+/// Round precise american odds to whole ones, using the step from config first.
+pub(crate) fn precise_american_to_american_custom(
+    value: Decimal,
+    config: &ConversionConfig,
+) -> Result<i32, ConversionError> {
+    round_american_to_step(value, config)
+        .round_dp_with_strategy(0, rounding_strategy(config))
+        .to_i32()
+        .ok_or(ConversionError::InvalidPreciseAmerican(value))
+}
+
+/// Round american odds to the step from config, never crossing evens.
+fn round_american_to_step(american: Decimal, config: &ConversionConfig) -> Decimal {
+    let step = config.american_step;
+    if step <= Decimal::ZERO {
+        return american;
+    }
+
+    let rounded = (american / step).round_dp_with_strategy(0, rounding_strategy(config)) * step;
+
+    // Coarse steps could round f. ex. -102 to -100 or 101 to 100, but never further
+    if rounded.abs() < Decimal::ONE_HUNDRED {
+        if american > Decimal::ZERO {
+            Decimal::ONE_HUNDRED
+        } else {
+            -Decimal::ONE_HUNDRED
+        }
     } else {
-        Err(ConversionError::InvalidDecimal)
+        rounded.normalize()
+    }
+}
+
+/// Convert from precise (decimal-valued) american to decimal with default parameters.
+pub fn precise_american_to_decimal(value: Decimal) -> Result<Decimal, ConversionError> {
+    with_default_config(|config| precise_american_to_decimal_custom(value, config))
+}
+
+/// Convert from precise (decimal-valued) american to decimal with custom parameters.
+///
+/// Whole values are converted the same way as `i32` american odds (using lookup tables).
+pub fn precise_american_to_decimal_custom(
+    value: Decimal,
+    config: &ConversionConfig,
+) -> Result<Decimal, ConversionError> {
+    if value.is_zero() {
+        return Err(ConversionError::AmericanZero);
+    }
+    if value.abs() < Decimal::ONE_HUNDRED {
+        return Err(ConversionError::InvalidPreciseAmerican(value));
+    }
+
+    if value.fract().is_zero()
+        && let Some(whole) = value.to_i32()
+    {
+        return american_to_decimal_custom(whole, config);
+    }
+
+    if value > Decimal::ZERO {
+        Ok(value / Decimal::ONE_HUNDRED + Decimal::ONE)
+    } else {
+        match config.price_rounding {
+            PriceRounding::Nearest => Ok(Decimal::ONE_HUNDRED / -value + Decimal::ONE),
+            rounding => ratio_to_decimal(american_ratio(value), rounding)
+                .ok_or(ConversionError::InvalidPreciseAmerican(value)),
+        }
     }
 }
 
 /// Convert from fractional to american with default parameters.
 pub fn fractional_to_american(num: u32, den: u32) -> Result<i32, ConversionError> {
-    fractional_to_american_custom(num, den, &ConversionConfig::default())
+    with_default_config(|config| fractional_to_american_custom(num, den, config))
 }
 
 /// Convert from fractional to american with custom parameters.
@@ -258,11 +834,65 @@ pub fn fractional_to_american_custom(
     den: u32,
     config: &ConversionConfig,
 ) -> Result<i32, ConversionError> {
+    fractional_to_american_with_method(num, den, config).map(|(american, _)| american)
+}
+
+/// Convert from fractional to american with custom parameters, explaining how the result was produced.
+pub fn fractional_to_american_explained(
+    num: u32,
+    den: u32,
+    config: &ConversionConfig,
+) -> Result<ConversionOutcome<'_, i32>, ConversionError> {
+    fractional_to_american_with_method(num, den, config)
+        .map(|(american, method)| explain(american, method, config))
+}
+
+fn fractional_to_american_with_method(
+    num: u32,
+    den: u32,
+    config: &ConversionConfig,
+) -> Result<(i32, Method), ConversionError> {
     if den == 0 {
-        return Err(ConversionError::DenominatorZero);
+        return Err(ConversionError::DenominatorZero { num });
+    }
+
+    let source = fraction_ratio(num, den);
+    if let Some(hit) = lookup(
+        &(num, den),
+        config,
+        get_fraction_to_american_map(),
+        get_fraction_to_american_extended_map(),
+        LookupTable::fraction_to_american_map,
+        |american| is_rounding_allowed(config, american_ratio(Decimal::from(*american)), source),
+    ) {
+        return Ok(hit);
     }
-    let decimal = Decimal::from(num) / Decimal::from(den) + Decimal::ONE;
-    decimal_to_american_custom(decimal, config)
+
+    let american = fractional_to_precise_american_custom(num, den, config)?
+        .round_dp_with_strategy(0, rounding_strategy(config))
+        .to_i32()
+        .ok_or(ConversionError::DecimalOverflow(
+            fractional_to_exact_decimal(num, den)?,
+        ))?;
+
+    Ok((american, Method::Formula))
+}
+
+/// Convert from fractional to precise american with custom parameters, computed from the exact fraction
+/// if the price is rounded in one direction.
+pub(crate) fn fractional_to_precise_american_custom(
+    num: u32,
+    den: u32,
+    config: &ConversionConfig,
+) -> Result<Decimal, ConversionError> {
+    let decimal = fractional_to_exact_decimal(num, den)?;
+    if config.price_rounding == PriceRounding::Nearest || num == 0 {
+        return normalized_decimal_to_precise_american(decimal, config);
+    }
+
+    let american = ratio_to_american(fraction_ratio(num, den), config.price_rounding)
+        .ok_or(ConversionError::DecimalOverflow(decimal))?;
+    Ok(round_american_to_step(american, config))
 }
 
 /// Normalize american odds (converts 1-99 to negative values, -1-99 to positive values).
@@ -279,22 +909,161 @@ pub fn normalize_american_odds(odds: i32) -> i32 {
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConversionError {
     /// American odds value cannot be zero.
     AmericanZero,
-    /// Denominator in fractional odds cannot be zero.
-    DenominatorZero,
+    /// American odds have to be at most -100 or at least +100.
+    InvalidAmerican(i32),
+    /// Precise american odds have to be at most -100 or at least +100.
+    InvalidPreciseAmerican(Decimal),
+    /// Denominator in fractional odds cannot be zero (holds the numerator).
+    DenominatorZero { num: u32 },
     /// Ran into overflow while computing decimal from or to decimal value.
-    DecimalOverflow,
+    DecimalOverflow(Decimal),
     /// Decimal odds cannot be less or equal 1.0
-    InvalidDecimal,
+    InvalidDecimal(Decimal),
+    /// Numerator or denominator computed from the decimal value doesn't fit in `u32`.
+    FractionOverflow(Decimal),
+    /// Decimal value is too close to 1.0 and the computed fraction would be zero.
+    FractionUnderflow(Decimal),
+    /// Fraction cannot be created from a negative value.
+    NegativeFraction(Decimal),
+    /// `max_denominator` in the config has to be positive to compute fractions.
+    ZeroMaxDenominator,
+    /// `plain_scale` in the config has to be positive to compute plain fractions.
+    ZeroPlainScale,
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionError::AmericanZero => write!(f, "american odds cannot be zero"),
+            ConversionError::InvalidAmerican(value) => {
+                write!(
+                    f,
+                    "american odds {value} have to be at most -100 or at least +100"
+                )
+            }
+            ConversionError::InvalidPreciseAmerican(value) => {
+                write!(
+                    f,
+                    "american odds {value} have to be at most -100 or at least +100"
+                )
+            }
+            ConversionError::DenominatorZero { num } => {
+                write!(f, "denominator of fractional odds {num}/0 cannot be zero")
+            }
+            ConversionError::DecimalOverflow(value) => {
+                write!(f, "overflow while converting decimal odds {value}")
+            }
+            ConversionError::InvalidDecimal(value) => {
+                write!(f, "decimal odds {value} have to be greater than 1.0")
+            }
+            ConversionError::FractionOverflow(value) => {
+                write!(f, "fraction computed from decimal odds {value} overflows")
+            }
+            ConversionError::FractionUnderflow(value) => {
+                write!(
+                    f,
+                    "decimal odds {value} are too close to 1.0 to compute a fraction"
+                )
+            }
+            ConversionError::NegativeFraction(value) => {
+                write!(f, "fraction cannot be negative, got {value}")
+            }
+            ConversionError::ZeroMaxDenominator => {
+                write!(f, "max denominator has to be positive")
+            }
+            ConversionError::ZeroPlainScale => write!(f, "plain scale has to be positive"),
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+impl ConversionError {
+    /// Report the decimal input of the caller instead of its normalised value.
+    fn with_decimal_input(self, input: Decimal) -> Self {
+        match self {
+            ConversionError::DecimalOverflow(_) => ConversionError::DecimalOverflow(input),
+            ConversionError::InvalidDecimal(_) => ConversionError::InvalidDecimal(input),
+            ConversionError::FractionOverflow(_) => ConversionError::FractionOverflow(input),
+            ConversionError::FractionUnderflow(_) => ConversionError::FractionUnderflow(input),
+            error => error,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CalculationError {
+    /// Odds could not be converted to decimal.
+    Conversion(ConversionError),
+    /// Stake cannot be negative.
+    NegativeStake,
+    /// Commission has to be in range [0, 1).
+    InvalidCommission,
+    /// Margin has to be in range [0, 1).
+    InvalidMargin,
+    /// Boost cannot lower the price.
+    InvalidBoost,
+    /// Probability has to be in range [0, 1].
+    InvalidProbability,
+    /// Selection index is out of the market range.
+    InvalidSelection,
+    /// At least one value is required.
+    EmptyInput,
+    /// Number of calibration bands has to be positive.
+    InvalidBands,
+}
+
+impl fmt::Display for CalculationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalculationError::Conversion(err) => write!(f, "{err}"),
+            CalculationError::NegativeStake => write!(f, "stake cannot be negative"),
+            CalculationError::InvalidCommission => {
+                write!(f, "commission has to be in range [0, 1)")
+            }
+            CalculationError::InvalidMargin => write!(f, "margin has to be in range [0, 1)"),
+            CalculationError::InvalidBoost => write!(f, "boost cannot lower the price"),
+            CalculationError::InvalidProbability => {
+                write!(f, "probability has to be in range [0, 1]")
+            }
+            CalculationError::InvalidSelection => {
+                write!(f, "selection is out of the market range")
+            }
+            CalculationError::EmptyInput => write!(f, "at least one value is required"),
+            CalculationError::InvalidBands => write!(f, "number of bands has to be positive"),
+        }
+    }
+}
+
+impl std::error::Error for CalculationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CalculationError::Conversion(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ConversionError> for CalculationError {
+    fn from(value: ConversionError) -> Self {
+        Self::Conversion(value)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::cmp::Ordering;
+
     use rust_decimal_macros::dec;
 
-    use crate::testing_helpers::assert_decimal_eq;
+    use crate::{
+        Odds, Provenance,
+        testing_helpers::{assert_decimal_eq, frac},
+    };
 
     use super::*;
 
@@ -378,6 +1147,605 @@ mod tests {
         assert!(decimal_to_american(dec!(-5.0)).is_err());
     }
 
+    #[test]
+    fn test_precise_american() {
+        assert_eq!(decimal_to_precise_american(dec!(1.8)), Ok(dec!(-125)));
+        assert_eq!(decimal_to_precise_american(dec!(2.375)), Ok(dec!(138)));
+
+        // Half-point step
+        let config = ConversionConfig::default().american_step(dec!(0.5));
+        assert_eq!(
+            decimal_to_precise_american_custom(dec!(1.8889), &config),
+            Ok(dec!(-112.5))
+        );
+        assert_eq!(
+            decimal_to_precise_american_custom(dec!(2.375), &config),
+            Ok(dec!(137.5))
+        );
+        // Whole odds are rounded again
+        assert_eq!(decimal_to_american_custom(dec!(2.375), &config), Ok(138));
+
+        // Step of 5
+        let config = ConversionConfig::default().american_step(dec!(5));
+        assert_eq!(decimal_to_american_custom(dec!(1.885), &config), Ok(-115));
+        assert_eq!(decimal_to_american_custom(dec!(2.42), &config), Ok(140));
+        // Never rounded past evens
+        assert_eq!(decimal_to_american_custom(dec!(1.98), &config), Ok(-100));
+        assert_eq!(decimal_to_american_custom(dec!(2.02), &config), Ok(100));
+
+        // No rounding
+        let config = ConversionConfig::default().american_step(Decimal::ZERO);
+        assert_eq!(
+            decimal_to_precise_american_custom(dec!(2.3755), &config),
+            Ok(dec!(137.55))
+        );
+
+        assert_eq!(precise_american_to_decimal(dec!(137.5)), Ok(dec!(2.375)));
+        assert_eq!(
+            precise_american_to_decimal(dec!(-112.5))
+                .unwrap()
+                .round_dp(4),
+            dec!(1.8889)
+        );
+        // Whole values use lookup tables
+        assert_eq!(precise_american_to_decimal(dec!(-110)), Ok(dec!(1.91)));
+        assert_eq!(
+            precise_american_to_decimal(dec!(-99.5)),
+            Err(ConversionError::InvalidPreciseAmerican(dec!(-99.5)))
+        );
+        assert_eq!(
+            precise_american_to_decimal(Decimal::ZERO),
+            Err(ConversionError::AmericanZero)
+        );
+    }
+
+    #[test]
+    fn test_precision_config() {
+        let config = ConversionConfig::default().no_lookup();
+        let with_tolerance = |tolerance| config.clone().fraction_tolerance(tolerance);
+
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(1.91), &config),
+            Ok(frac(91, 100))
+        );
+        assert_eq!(
+            decimal_to_fractional_custom(
+                dec!(1.91),
+                &with_tolerance(FractionTolerance::Absolute(dec!(0.0001)))
+            ),
+            Ok(frac(91, 100))
+        );
+        assert_eq!(
+            decimal_to_fractional_custom(
+                dec!(1.91),
+                &with_tolerance(FractionTolerance::Relative(dec!(0.01)))
+            ),
+            Ok(frac(10, 11))
+        );
+        assert_eq!(
+            decimal_to_fractional_custom(
+                dec!(1.91),
+                &with_tolerance(FractionTolerance::ImpliedProbability(dec!(0.005)))
+            ),
+            Ok(frac(10, 11))
+        );
+
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(2.7183), &config),
+            Ok(frac(122, 71))
+        );
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(2.7183), &config.clone().max_denominator(20)),
+            Ok(frac(12, 7))
+        );
+        assert_eq!(
+            decimal_to_fractional_simplify_custom(
+                dec!(2.7183),
+                &with_tolerance(FractionTolerance::Absolute(dec!(0.05)))
+            ),
+            Ok(frac(7, 4))
+        );
+
+        // Coarse tolerance never gives zero numerator, but too small max denominator does
+        assert_eq!(
+            decimal_to_fractional_custom(
+                dec!(1.0123),
+                &with_tolerance(FractionTolerance::Absolute(dec!(0.05)))
+            ),
+            Ok(frac(1, 81))
+        );
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(1.0123), &config.clone().max_denominator(20)),
+            Err(ConversionError::FractionUnderflow(dec!(1.0123)))
+        );
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(2.5), &config.clone().max_denominator(0)),
+            Err(ConversionError::ZeroMaxDenominator)
+        );
+
+        let plain = config.plain_fraction_strategy();
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(1.3183), &plain.clone().plain_scale(100)),
+            Ok(frac(8, 25))
+        );
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(1.3183), &plain.clone().plain_scale(10000)),
+            Ok(frac(3183, 10000))
+        );
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(1.3183), &plain.plain_scale(0)),
+            Err(ConversionError::ZeroPlainScale)
+        );
+    }
+
+    #[test]
+    fn test_preferred_denominators() {
+        let config = ConversionConfig::default()
+            .no_lookup()
+            .fraction_strategy(FractionStrategy::PreferredDenominators);
+
+        // Fractions are kept as on the board, not reduced
+        let board = config
+            .clone()
+            .preferred_denominators(&[1, 2, 4, 5, 10, 20, 40]);
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(3.125), &board),
+            Ok(frac(85, 40))
+        );
+        // 17/8 and 85/40 are equal, 8 is preferred first
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(3.125), &config),
+            Ok(frac(17, 8))
+        );
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(1.6667), &config),
+            Ok(frac(10, 15))
+        );
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(1.727), &config),
+            Ok(frac(8, 11))
+        );
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(3.7), &config),
+            Ok(frac(27, 10))
+        );
+
+        // 11/4 and 22/8 are equally close, earlier denominator wins
+        let quarters = config.clone().preferred_denominators(&[1, 2, 4, 8]);
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(3.7), &quarters),
+            Ok(frac(11, 4))
+        );
+        assert_eq!(
+            decimal_to_fractional_custom(
+                dec!(3.7),
+                &quarters.clone().price_rounding(PriceRounding::Shorten)
+            ),
+            Ok(frac(21, 8))
+        );
+        assert_eq!(
+            decimal_to_fractional_custom(
+                dec!(3.7),
+                &quarters.clone().price_rounding(PriceRounding::Lengthen)
+            ),
+            Ok(frac(11, 4))
+        );
+
+        // Shortened price never has lower implied probability, lengthened never higher
+        for value in [
+            dec!(1.01),
+            dec!(1.3),
+            dec!(1.91),
+            dec!(2.38),
+            dec!(4.33),
+            dec!(17.5),
+        ] {
+            let shorten = config.clone().price_rounding(PriceRounding::Shorten);
+            let lengthen = config.clone().price_rounding(PriceRounding::Lengthen);
+            let short = decimal_to_fractional_custom(value, &shorten).unwrap();
+            let long = decimal_to_fractional_custom(value, &lengthen).unwrap();
+            assert!(
+                short.to_decimal() <= value - Decimal::ONE,
+                "{value}: {short}"
+            );
+            assert!(long.to_decimal() >= value - Decimal::ONE, "{value}: {long}");
+        }
+
+        // No fraction below the price with denominators up to 10
+        let shorten = quarters
+            .preferred_denominators(vec![2, 10])
+            .price_rounding(PriceRounding::Shorten);
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(1.05), &shorten),
+            Err(ConversionError::FractionUnderflow(dec!(1.05)))
+        );
+        assert_eq!(
+            decimal_to_fractional_explained(dec!(1.2), &shorten).map(|outcome| outcome.provenance),
+            Ok(Provenance::PreferredDenominators)
+        );
+    }
+
+    #[test]
+    fn test_price_rounding() {
+        let shorten = ConversionConfig::default().price_rounding(PriceRounding::Shorten);
+        let lengthen = ConversionConfig::default().price_rounding(PriceRounding::Lengthen);
+
+        // 1.91 from the lookup table is longer than -110 (1.9090...)
+        assert_eq!(american_to_decimal(-110), Ok(dec!(1.91)));
+        assert_eq!(
+            american_to_decimal_custom(-110, &shorten),
+            Ok(dec!(1.9090909090909090909090909090))
+        );
+        assert_eq!(american_to_decimal_custom(-110, &lengthen), Ok(dec!(1.91)));
+
+        // 4/6 is shorter than 1.67, 5/6 is longer than 1.83
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(1.67), &shorten),
+            Ok(frac(4, 6))
+        );
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(1.83), &shorten),
+            Ok(frac(83, 100))
+        );
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(1.83), &lengthen),
+            Ok(frac(5, 6))
+        );
+
+        // Continued fractions stop at 5/2, which is longer than 3.4999,
+        // 47/19 is the closest shorter fraction with denominator up to 20
+        let no_lookup = shorten.clone().no_lookup().max_denominator(20);
+        assert_eq!(decimal_to_fractional(dec!(3.4999)), Ok(frac(5, 2)));
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(3.4999), &no_lookup),
+            Ok(frac(47, 19))
+        );
+
+        assert_eq!(decimal_to_american(dec!(1.87)), Ok(-115));
+        assert_eq!(decimal_to_american_custom(dec!(1.87), &shorten), Ok(-115));
+        assert_eq!(decimal_to_american_custom(dec!(1.87), &lengthen), Ok(-114));
+        assert_eq!(
+            decimal_to_precise_american_custom(
+                dec!(2.376),
+                &shorten.clone().american_step(dec!(0.5))
+            ),
+            Ok(dec!(137.5))
+        );
+        assert_eq!(fractional_to_american_custom(8, 13, &shorten), Ok(-163));
+        assert_eq!(fractional_to_american_custom(8, 13, &lengthen), Ok(-162));
+    }
+
+    #[test]
+    fn test_shortened_prices_never_overpay() {
+        let configs = [
+            ConversionConfig::default(),
+            ConversionConfig::default().extended_lookup(),
+            ConversionConfig::default().no_lookup(),
+            ConversionConfig::default().no_lookup().max_denominator(100),
+            ConversionConfig::default()
+                .no_lookup()
+                .plain_fraction_strategy(),
+            ConversionConfig::default()
+                .no_lookup()
+                .fraction_strategy(FractionStrategy::PreferredDenominators),
+            ConversionConfig::default().american_step(dec!(5)),
+            ConversionConfig::default().american_step(dec!(0.5)),
+        ];
+
+        let americans = (-1000..=-100).chain(100..=1000).step_by(3);
+        let decimals = (101..=2000).step_by(7).map(|value| Decimal::new(value, 2));
+        let fractions = (1..=40).flat_map(|num| (1..=40).map(move |den| (num, den)));
+        let precise = [dec!(-112.5), dec!(-137.25), dec!(137.5), dec!(-333.3)];
+
+        for (rounding, expected) in [
+            (PriceRounding::Shorten, Ordering::Greater),
+            (PriceRounding::Lengthen, Ordering::Less),
+        ] {
+            for config in &configs {
+                let config = config.clone().price_rounding(rounding);
+                // Converted price has at least the implied probability of the source when shortened
+                let check = |source: Odds, converted: Odds| {
+                    assert_ne!(
+                        converted.cmp(&source),
+                        expected.reverse(),
+                        "{source} converted to {converted} with {config:?}"
+                    );
+                };
+
+                for value in americans.clone() {
+                    let source = Odds::american(value).unwrap();
+                    let decimal = american_to_decimal_custom(value, &config).unwrap();
+                    check(source, Odds::decimal(decimal).unwrap());
+                    let fraction = american_to_fractional_custom(value, &config).unwrap();
+                    check(source, fraction.try_into().unwrap());
+                }
+
+                for value in decimals.clone() {
+                    let source = Odds::decimal(value).unwrap();
+                    let fraction = decimal_to_fractional_custom(value, &config).unwrap();
+                    check(source, fraction.try_into().unwrap());
+                    let american = decimal_to_american_custom(value, &config).unwrap();
+                    check(source, Odds::american(american).unwrap());
+                    let american = decimal_to_precise_american_custom(value, &config).unwrap();
+                    check(source, Odds::precise_american(american).unwrap());
+                }
+
+                for (num, den) in fractions.clone() {
+                    let source = Odds::fractional(num, den).unwrap();
+                    let decimal = fractional_to_decimal_custom(num, den, &config).unwrap();
+                    check(source, Odds::decimal(decimal).unwrap());
+                    let american = fractional_to_american_custom(num, den, &config).unwrap();
+                    check(source, Odds::american(american).unwrap());
+                    let american = source.to_precise_american_custom(&config).unwrap();
+                    check(source, Odds::precise_american(american).unwrap());
+                }
+
+                for value in precise {
+                    let source = Odds::precise_american(value).unwrap();
+                    check(
+                        source,
+                        Odds::decimal(source.to_decimal_custom(&config).unwrap()).unwrap(),
+                    );
+                    check(
+                        source,
+                        source
+                            .to_fractional_custom(&config)
+                            .unwrap()
+                            .try_into()
+                            .unwrap(),
+                    );
+                    check(
+                        source,
+                        Odds::american(source.to_american_custom(&config).unwrap()).unwrap(),
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_explained() {
+        let default = ConversionConfig::default();
+        let outcome = american_to_fractional_explained(-150, &default).unwrap();
+        assert_eq!(outcome.value, frac(4, 6));
+        assert_eq!(outcome.provenance, Provenance::BasicLookup);
+        assert!(outcome.is_lookup());
+        assert_eq!(outcome.config.lookup_tables_variant, LookupVariant::Basic);
+
+        // Without lookup -150 goes through 1.6667 and continued fractions
+        let config = ConversionConfig::default().no_lookup();
+        let outcome = american_to_fractional_explained(-150, &config).unwrap();
+        assert_eq!(outcome.value, frac(2, 3));
+        let Provenance::Simplify { convergents, error } = outcome.provenance else {
+            panic!("Expected Provenance::Simplify");
+        };
+        assert_eq!(convergents, [frac(0, 1), frac(1, 1), frac(2, 3)]);
+        assert!(error < dec!(0.0001));
+        // Config is borrowed, not cloned
+        assert!(std::ptr::eq(outcome.config, &config));
+
+        let extended = ConversionConfig::default().extended_lookup();
+        let outcome = decimal_to_fractional_explained(dec!(1.0013), &extended).unwrap();
+        assert_eq!(outcome.value, frac(1, 750));
+        assert_eq!(outcome.provenance, Provenance::ExtendedLookup);
+
+        // Found in basic table even if extended lookup is enabled
+        let outcome = decimal_to_fractional_explained(dec!(1.25), &extended).unwrap();
+        assert_eq!(outcome.provenance, Provenance::BasicLookup);
+
+        let plain = ConversionConfig::default().plain_fraction_strategy();
+        let outcome = decimal_to_fractional_explained(dec!(1.016), &plain).unwrap();
+        assert_eq!(outcome.value, frac(2, 125));
+        assert_eq!(outcome.provenance, Provenance::Plain);
+        assert!(!outcome.is_lookup());
+
+        let outcome = american_to_decimal_explained(-110, &default).unwrap();
+        assert_eq!(outcome.value, dec!(1.91));
+        assert_eq!(outcome.provenance, Provenance::BasicLookup);
+
+        let outcome = american_to_decimal_explained(-110, &config).unwrap();
+        assert_decimal_eq(outcome.value, dec!(1.9091));
+        assert_eq!(outcome.provenance, Provenance::Formula);
+
+        let outcome = decimal_to_american_explained(dec!(1.5), &config).unwrap();
+        assert_eq!(outcome.value, -200);
+        assert_eq!(outcome.provenance, Provenance::Formula);
+
+        let outcome = fractional_to_american_explained(4, 6, &config).unwrap();
+        assert_eq!(outcome.value, -150);
+
+        assert_eq!(
+            decimal_to_fractional_explained(dec!(1.0), &config).err(),
+            Some(ConversionError::InvalidDecimal(dec!(1.0)))
+        );
+    }
+
+    #[test]
+    fn test_reverse_lookup() {
+        let config = ConversionConfig::default();
+        let no_lookup = config.clone().no_lookup();
+
+        // UK board
+        assert_eq!(fractional_to_american(10, 11), Ok(-110));
+        assert_eq!(fractional_to_decimal(10, 11), Ok(dec!(1.91)));
+        assert_eq!(fractional_to_decimal(4, 6), Ok(dec!(1.67)));
+        assert_decimal_eq(fractional_to_decimal(2, 3).unwrap(), dec!(1.6667));
+        assert_decimal_eq(
+            fractional_to_decimal_custom(10, 11, &no_lookup).unwrap(),
+            dec!(1.9091),
+        );
+        assert_eq!(decimal_to_american(dec!(1.67)), Ok(-150));
+        assert_eq!(decimal_to_american_custom(dec!(1.67), &no_lookup), Ok(-149));
+        assert_eq!(fractional_to_american(15, 8), Ok(188));
+
+        // Every table entry converts back to its key
+        for (decimal, (num, den)) in get_decimal_to_fraction_map().iter() {
+            assert_eq!(fractional_to_decimal(*num, *den), Ok(decimal));
+        }
+        for (american, (num, den)) in get_american_to_fraction_map().iter() {
+            assert_eq!(fractional_to_american(*num, *den), Ok(american));
+        }
+        for (american, decimal) in get_american_to_decimal_map().iter() {
+            assert_eq!(decimal_to_american(*decimal), Ok(american));
+        }
+
+        let outcome = fractional_to_decimal_explained(10, 11, &config).unwrap();
+        assert_eq!(outcome.provenance, Provenance::BasicLookup);
+        let outcome = fractional_to_decimal_explained(10, 11, &no_lookup).unwrap();
+        assert_eq!(outcome.provenance, Provenance::Formula);
+        let outcome = decimal_to_american_explained(dec!(1.91), &config).unwrap();
+        assert_eq!(outcome.provenance, Provenance::BasicLookup);
+        let outcome = fractional_to_american_explained(10, 11, &config).unwrap();
+        assert_eq!(outcome.provenance, Provenance::BasicLookup);
+
+        let extended = config.clone().extended_lookup();
+        let outcome = fractional_to_decimal_explained(1, 750, &extended).unwrap();
+        assert_eq!(outcome.value, dec!(1.0013));
+        assert_eq!(outcome.provenance, Provenance::ExtendedLookup);
+        assert_ne!(fractional_to_decimal(1, 750), Ok(dec!(1.0013)));
+
+        // 1.91 is longer than 10/11
+        let shorten = config.clone().price_rounding(PriceRounding::Shorten);
+        assert!(fractional_to_decimal_custom(10, 11, &shorten).unwrap() < dec!(1.91));
+        assert_eq!(decimal_to_american_custom(dec!(1.67), &shorten), Ok(-150));
+        let lengthen = config.price_rounding(PriceRounding::Lengthen);
+        assert_eq!(
+            fractional_to_decimal_custom(10, 11, &lengthen),
+            Ok(dec!(1.91))
+        );
+        assert_eq!(decimal_to_american_custom(dec!(1.67), &lengthen), Ok(-149));
+
+        assert_eq!(
+            fractional_to_decimal(10, 0),
+            Err(ConversionError::DenominatorZero { num: 10 })
+        );
+        assert_eq!(
+            fractional_to_american(10, 0),
+            Err(ConversionError::DenominatorZero { num: 10 })
+        );
+    }
+
+    #[test]
+    fn test_decimal_normalization() {
+        use crate::{
+            lookup_decimal_to_fraction_with_config, lookup_fraction_at_or_below_with_config,
+        };
+
+        let configs = [
+            ConversionConfig::default(),
+            ConversionConfig::default().extended_lookup(),
+            ConversionConfig::default().no_lookup(),
+            ConversionConfig::default().price_rounding(PriceRounding::Shorten),
+            ConversionConfig::default()
+                .round_decimal_input(2, RoundingStrategy::MidpointAwayFromZero),
+        ];
+        let decimals = get_decimal_to_fraction_map()
+            .iter()
+            .chain(get_decimal_to_fraction_extended_map().iter())
+            .map(|(decimal, _)| decimal)
+            .chain((101..=1000).step_by(7).map(|value| Decimal::new(value, 2)));
+
+        // Trailing zeros never change the result
+        for decimal in decimals {
+            let mut padded = decimal;
+            padded.rescale(decimal.scale() + 3);
+            for config in &configs {
+                assert_eq!(
+                    decimal_to_fractional_custom(padded, config),
+                    decimal_to_fractional_custom(decimal, config),
+                    "{padded}"
+                );
+                assert_eq!(
+                    decimal_to_american_custom(padded, config),
+                    decimal_to_american_custom(decimal, config),
+                    "{padded}"
+                );
+                assert_eq!(
+                    decimal_to_precise_american_custom(padded, config),
+                    decimal_to_precise_american_custom(decimal, config),
+                    "{padded}"
+                );
+                assert_eq!(
+                    lookup_decimal_to_fraction_with_config(padded, config),
+                    lookup_decimal_to_fraction_with_config(decimal, config),
+                    "{padded}"
+                );
+                assert_eq!(
+                    lookup_fraction_at_or_below_with_config(padded, config),
+                    lookup_fraction_at_or_below_with_config(decimal, config),
+                    "{padded}"
+                );
+            }
+        }
+
+        // Rounded input behaves as the rounded value everywhere
+        let config = ConversionConfig::default();
+        let rounded = config
+            .clone()
+            .round_decimal_input(2, RoundingStrategy::MidpointAwayFromZero);
+        assert_eq!(rounded.normalize_decimal(dec!(1.667)), dec!(1.67));
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(1.667), &rounded),
+            Ok(frac(4, 6))
+        );
+        assert_eq!(decimal_to_fractional(dec!(1.67)), Ok(frac(4, 6)));
+        assert_eq!(decimal_to_fractional(dec!(1.667)), Ok(frac(2, 3)));
+        assert_eq!(decimal_to_american_custom(dec!(1.667), &rounded), Ok(-150));
+        assert_eq!(
+            decimal_to_precise_american_custom(dec!(1.667), &rounded),
+            decimal_to_precise_american(dec!(1.67))
+        );
+        assert_eq!(
+            lookup_decimal_to_fraction_with_config(dec!(1.667), &rounded),
+            Some((4, 6))
+        );
+        assert_eq!(
+            lookup_decimal_to_fraction_with_config(dec!(1.667), &config),
+            None
+        );
+        assert_eq!(
+            lookup_fraction_at_or_below_with_config(dec!(1.665), &rounded),
+            Some((4, 6))
+        );
+        assert_eq!(
+            lookup_fraction_at_or_below_with_config(dec!(1.665), &config),
+            Some((8, 13))
+        );
+        let outcome = decimal_to_fractional_explained(dec!(1.667), &rounded).unwrap();
+        assert_eq!(outcome.provenance, Provenance::BasicLookup);
+
+        // Errors carry the input, not the value it was rounded to
+        let invalid = Some(ConversionError::InvalidDecimal(dec!(1.004)));
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(1.004), &rounded).err(),
+            invalid
+        );
+        assert_eq!(
+            decimal_to_fractional_explained(dec!(1.004), &rounded).err(),
+            invalid
+        );
+        assert_eq!(
+            decimal_to_american_custom(dec!(1.004), &rounded).err(),
+            invalid
+        );
+        assert_eq!(
+            decimal_to_precise_american_custom(dec!(1.004), &rounded).err(),
+            invalid
+        );
+
+        // Rounding strategy of the input
+        let truncated =
+            ConversionConfig::default().round_decimal_input(1, RoundingStrategy::ToZero);
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(2.59), &truncated),
+            Ok(frac(6, 4))
+        );
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(1.04), &truncated),
+            Err(ConversionError::InvalidDecimal(dec!(1.04)))
+        );
+    }
+
     #[test]
     fn test_fractional_to_american() {
         // Real-world examples (Favorites)
@@ -399,35 +1767,35 @@ mod tests {
         // Invalid cases
         assert_eq!(
             fractional_to_american(10, 0),
-            Err(ConversionError::DenominatorZero)
+            Err(ConversionError::DenominatorZero { num: 10 })
         );
     }
 
     #[test]
     fn test_american_to_fractional() {
         // Real-world examples (Favorites)
-        assert_eq!(american_to_fractional(-200), Ok((1, 2)));
-        assert_eq!(american_to_fractional(-500), Ok((1, 5)));
+        assert_eq!(american_to_fractional(-200), Ok(frac(1, 2)));
+        assert_eq!(american_to_fractional(-500), Ok(frac(1, 5)));
 
         // Traditional UK fraction
-        assert_eq!(american_to_fractional(-150), Ok((4, 6)));
+        assert_eq!(american_to_fractional(-150), Ok(frac(4, 6)));
 
         // The same without lookup table
         assert_eq!(
             american_to_fractional_custom(-150, &ConversionConfig::default().no_lookup()),
-            Ok((2, 3))
+            Ok(frac(2, 3))
         );
 
         // Real-world examples (Underdogs)
-        assert_eq!(american_to_fractional(100), Ok((1, 1)));
-        assert_eq!(american_to_fractional(250), Ok((5, 2)));
-        assert_eq!(american_to_fractional(900), Ok((9, 1)));
-        assert_eq!(american_to_fractional(1200), Ok((12, 1)));
+        assert_eq!(american_to_fractional(100), Ok(frac(1, 1)));
+        assert_eq!(american_to_fractional(250), Ok(frac(5, 2)));
+        assert_eq!(american_to_fractional(900), Ok(frac(9, 1)));
+        assert_eq!(american_to_fractional(1200), Ok(frac(12, 1)));
 
         // Unrealistic / Edge cases
-        assert_eq!(american_to_fractional(50000), Ok((500, 1)));
-        assert_eq!(american_to_fractional(-110), Ok((10, 11))); // common case
-        assert_eq!(american_to_fractional(-1000), Ok((1, 10)));
+        assert_eq!(american_to_fractional(50000), Ok(frac(500, 1)));
+        assert_eq!(american_to_fractional(-110), Ok(frac(10, 11))); // common case
+        assert_eq!(american_to_fractional(-1000), Ok(frac(1, 10)));
 
         // Note: american_to_fractional(0) will currently cause a panic
         // because of `unwrap_or(Decimal::ZERO)` followed by a conversion
@@ -441,14 +1809,14 @@ mod tests {
     #[test]
     fn test_decimal_to_fractional() {
         // Existing tests
-        assert_eq!(super::decimal_to_fractional(dec!(1.3)), Ok((3, 10)));
-        assert_eq!(super::decimal_to_fractional(dec!(1.33)), Ok((1, 3)));
-        assert_eq!(super::decimal_to_fractional(dec!(1.333)), Ok((1, 3)));
-        assert_eq!(super::decimal_to_fractional(dec!(1.3333)), Ok((1, 3)));
-        assert_eq!(super::decimal_to_fractional(dec!(1.3337)), Ok((1, 3)));
-        assert_eq!(super::decimal_to_fractional(dec!(1.25)), Ok((1, 4)));
-        assert_eq!(super::decimal_to_fractional(dec!(4.1)), Ok((31, 10)));
-        assert_eq!(super::decimal_to_fractional(dec!(100.5)), Ok((199, 2)));
+        assert_eq!(super::decimal_to_fractional(dec!(1.3)), Ok(frac(3, 10)));
+        assert_eq!(super::decimal_to_fractional(dec!(1.33)), Ok(frac(1, 3)));
+        assert_eq!(super::decimal_to_fractional(dec!(1.333)), Ok(frac(1, 3)));
+        assert_eq!(super::decimal_to_fractional(dec!(1.3333)), Ok(frac(1, 3)));
+        assert_eq!(super::decimal_to_fractional(dec!(1.3337)), Ok(frac(1, 3)));
+        assert_eq!(super::decimal_to_fractional(dec!(1.25)), Ok(frac(1, 4)));
+        assert_eq!(super::decimal_to_fractional(dec!(4.1)), Ok(frac(31, 10)));
+        assert_eq!(super::decimal_to_fractional(dec!(100.5)), Ok(frac(199, 2)));
 
         // Gives 1/3 from lookup tables
         assert_eq!(
@@ -456,7 +1824,7 @@ mod tests {
                 dec!(1.33),
                 &ConversionConfig::default().plain_fraction_strategy()
             ),
-            Ok((1, 3))
+            Ok(frac(1, 3))
         );
 
         // Gives 33/100 with lookup tables disabled
@@ -467,7 +1835,7 @@ mod tests {
                     .plain_fraction_strategy()
                     .no_lookup()
             ),
-            Ok((33, 100))
+            Ok(frac(33, 100))
         );
 
         // No lookup for 1.333
@@ -476,24 +1844,82 @@ mod tests {
                 dec!(1.333),
                 &ConversionConfig::default().plain_fraction_strategy()
             ),
-            Ok((333, 1000))
+            Ok(frac(333, 1000))
         );
 
         // Additional real-world cases
-        assert_eq!(super::decimal_to_fractional(dec!(1.5)), Ok((1, 2)));
-        assert_eq!(super::decimal_to_fractional(dec!(2.0)), Ok((1, 1)));
-        assert_eq!(super::decimal_to_fractional(dec!(3.5)), Ok((5, 2)));
-        assert_eq!(super::decimal_to_fractional(dec!(1.8)), Ok((4, 5)));
-        assert_eq!(super::decimal_to_fractional(dec!(11.0)), Ok((10, 1)));
+        assert_eq!(super::decimal_to_fractional(dec!(1.5)), Ok(frac(1, 2)));
+        assert_eq!(super::decimal_to_fractional(dec!(2.0)), Ok(frac(1, 1)));
+        assert_eq!(super::decimal_to_fractional(dec!(3.5)), Ok(frac(5, 2)));
+        assert_eq!(super::decimal_to_fractional(dec!(1.8)), Ok(frac(4, 5)));
+        assert_eq!(super::decimal_to_fractional(dec!(11.0)), Ok(frac(10, 1)));
 
         // Edge cases
-        assert_eq!(super::decimal_to_fractional(dec!(1.001)), Ok((1, 1000)));
+        assert_eq!(super::decimal_to_fractional(dec!(1.001)), Ok(frac(1, 1000)));
         assert_eq!(
             super::decimal_to_fractional(dec!(1.0)),
-            Err(ConversionError::InvalidDecimal)
+            Err(ConversionError::InvalidDecimal(dec!(1.0)))
         );
     }
 
+    #[test]
+    fn test_fraction_overflow() {
+        let plain = ConversionConfig::default().plain_fraction_strategy();
+
+        // Scale is kept whole for values whose numerator has bigger powers of 2 or 5
+        assert_eq!(
+            decimal_to_fractional_plain(dec!(1.016), &plain),
+            Ok(frac(2, 125))
+        );
+        assert_eq!(
+            decimal_to_fractional_plain(dec!(21.0), &plain),
+            Ok(frac(20, 1))
+        );
+
+        assert_eq!(
+            decimal_to_fractional_plain(dec!(10000000000), &plain),
+            Err(ConversionError::FractionOverflow(dec!(10000000000)))
+        );
+        assert_eq!(
+            decimal_to_fractional_plain(dec!(1.0004), &plain),
+            Err(ConversionError::FractionUnderflow(dec!(1.0004)))
+        );
+
+        assert_eq!(
+            decimal_to_fractional_simplify(dec!(5000000000.5)),
+            Err(ConversionError::FractionOverflow(dec!(5000000000.5)))
+        );
+        assert_eq!(
+            decimal_to_fractional_simplify(dec!(1.00001)),
+            Err(ConversionError::FractionUnderflow(dec!(1.00001)))
+        );
+    }
+
+    #[test]
+    fn test_error_display() {
+        assert_eq!(
+            ConversionError::InvalidDecimal(dec!(0.5)).to_string(),
+            "decimal odds 0.5 have to be greater than 1.0"
+        );
+        assert_eq!(
+            ConversionError::DenominatorZero { num: 5 }.to_string(),
+            "denominator of fractional odds 5/0 cannot be zero"
+        );
+
+        // Composes with `?` into boxed errors
+        fn convert() -> Result<i32, Box<dyn std::error::Error>> {
+            Ok(decimal_to_american(dec!(0.9))?)
+        }
+        assert_eq!(
+            convert().unwrap_err().to_string(),
+            "decimal odds 0.9 have to be greater than 1.0"
+        );
+
+        let err = CalculationError::from(ConversionError::AmericanZero);
+        assert_eq!(err.to_string(), "american odds cannot be zero");
+        assert!(std::error::Error::source(&err).is_some());
+    }
+
     #[test]
     fn test_normalize_american_odds() {
         // Should not change
@@ -520,298 +1946,298 @@ mod tests {
     #[rustfmt::skip]
     fn extended_lookup_test() {
         let config = &ConversionConfig::default().extended_lookup();
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0010), config), Ok((1, 1000)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0013), config), Ok((1, 750)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0020), config), Ok((1, 500)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0025), config), Ok((1, 400)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0030), config), Ok((1, 300)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0040), config), Ok((1, 250)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0050), config), Ok((1, 200)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0070), config), Ok((1, 150)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0100), config), Ok((1, 100)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0120), config), Ok((1, 80)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0150), config), Ok((1, 66)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0200), config), Ok((1, 50)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0250), config), Ok((1, 40)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0300), config), Ok((1, 33)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0400), config), Ok((1, 25)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0500), config), Ok((1, 20)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0550), config), Ok((1, 18)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0600), config), Ok((1, 16)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0700), config), Ok((1, 14)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0800), config), Ok((1, 12)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0900), config), Ok((1, 11)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.1000), config), Ok((1, 10)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.1100), config), Ok((1, 9)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.1200), config), Ok((1, 8)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.1300), config), Ok((2, 15)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.1400), config), Ok((1, 7)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.1500), config), Ok((2, 13)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.1600), config), Ok((1, 6)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.1800), config), Ok((2, 11))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.1900), config), Ok((19, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.2000), config), Ok((1, 5)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.2100), config), Ok((21, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.2200), config), Ok((2, 9)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.2300), config), Ok((23, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.2400), config), Ok((6, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.2500), config), Ok((1, 4)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.2600), config), Ok((13, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.2700), config), Ok((27, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.2900), config), Ok((2, 7)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.3000), config), Ok((3, 10)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.3100), config), Ok((31, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.3200), config), Ok((8, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.3300), config), Ok((1, 3)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.3400), config), Ok((17, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.3500), config), Ok((7, 20))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.3600), config), Ok((4, 11)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.3700), config), Ok((37, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.3800), config), Ok((19, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.3900), config), Ok((39, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.4000), config), Ok((2, 5)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.4100), config), Ok((41, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.4200), config), Ok((21, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.4300), config), Ok((43, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.4400), config), Ok((4, 9)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.4500), config), Ok((9, 20))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.4600), config), Ok((23, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.4700), config), Ok((40, 85)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.4800), config), Ok((12, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.4900), config), Ok((49, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.5000), config), Ok((1, 2)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.5100), config), Ok((51, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.5200), config), Ok((13, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.5300), config), Ok((8, 15)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.5400), config), Ok((27, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.5500), config), Ok((11, 20))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.5600), config), Ok((14, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.5700), config), Ok((4, 7)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.5800), config), Ok((29, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.5900), config), Ok((59, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.6000), config), Ok((3, 5))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.6100), config), Ok((8, 13)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.6300), config), Ok((63, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.6400), config), Ok((16, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.6500), config), Ok((13, 20))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.6600), config), Ok((4, 6)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.6800), config), Ok((34, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.6900), config), Ok((69, 100)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.7000), config), Ok((7, 10))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.7100), config), Ok((71, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.7200), config), Ok((8, 11)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.7400), config), Ok((37, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.7500), config), Ok((3, 4)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.7600), config), Ok((19, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.7700), config), Ok((77, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.7800), config), Ok((39, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.7900), config), Ok((79, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.8000), config), Ok((4, 5)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.8100), config), Ok((81, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.8200), config), Ok((41, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.8300), config), Ok((5, 6)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.8400), config), Ok((21, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.8500), config), Ok((17, 20))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.8600), config), Ok((20, 23))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.8700), config), Ok((87, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.8800), config), Ok((22, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.8900), config), Ok((89, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.9000), config), Ok((9, 10)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.9100), config), Ok((10, 11)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.9200), config), Ok((23, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.9300), config), Ok((93, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.9400), config), Ok((47, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.9500), config), Ok((20, 21)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.9600), config), Ok((24, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.9700), config), Ok((97, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.9800), config), Ok((49, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1.9900), config), Ok((99, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.0000), config), Ok((1, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.0100), config), Ok((101, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.0200), config), Ok((51, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.0300), config), Ok((103, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.0400), config), Ok((26, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.0500), config), Ok((21, 20)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.0600), config), Ok((53, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.0700), config), Ok((107, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.0800), config), Ok((27, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.0900), config), Ok((109, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.1000), config), Ok((11, 10)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.1100), config), Ok((111, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.1200), config), Ok((28, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.1300), config), Ok((113, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.1400), config), Ok((57, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.1500), config), Ok((23, 20)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.1600), config), Ok((29, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.1700), config), Ok((117, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.1800), config), Ok((59, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.1900), config), Ok((119, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.2000), config), Ok((6, 5)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.2100), config), Ok((121, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.2200), config), Ok((61, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.2300), config), Ok((123, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.2400), config), Ok((31, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.2500), config), Ok((5, 4)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.2600), config), Ok((63, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.2700), config), Ok((127, 100))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.2800), config), Ok((32, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.3000), config), Ok((13, 10)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.3200), config), Ok((33, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.3400), config), Ok((67, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.3500), config), Ok((27, 20))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.3600), config), Ok((34, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.3700), config), Ok((11, 8)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.4000), config), Ok((7, 5)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.4200), config), Ok((71, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.4400), config), Ok((36, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.4500), config), Ok((29, 20)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.4600), config), Ok((73, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.4800), config), Ok((37, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.5000), config), Ok((6, 4)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.5200), config), Ok((38, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.5400), config), Ok((77, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.5600), config), Ok((39, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.5800), config), Ok((79, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.6000), config), Ok((8, 5)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.6200), config), Ok((13, 8)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.6400), config), Ok((41, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.6600), config), Ok((83, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.6800), config), Ok((42, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.7000), config), Ok((17, 10)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.7200), config), Ok((43, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.7400), config), Ok((87, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.7500), config), Ok((7, 4)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.7600), config), Ok((44, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.7800), config), Ok((89, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.8000), config), Ok((9, 5)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.8200), config), Ok((91, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.8400), config), Ok((46, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.8600), config), Ok((93, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.8700), config), Ok((15, 8))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.8800), config), Ok((15, 8))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.9000), config), Ok((19, 10)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.9200), config), Ok((48, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.9400), config), Ok((97, 50)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.9600), config), Ok((49, 25))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(2.9800), config), Ok((99, 50))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(3.0000), config), Ok((2, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(3.0500), config), Ok((41, 20))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(3.1000), config), Ok((21, 10)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(3.1250), config), Ok((85, 40)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(3.1500), config), Ok((43, 20))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(3.2000), config), Ok((11, 5)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(3.2500), config), Ok((9, 4)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(3.3000), config), Ok((23, 10)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(3.3500), config), Ok((47, 20))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(3.4000), config), Ok((12, 5)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(3.4500), config), Ok((49, 20))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(3.5000), config), Ok((5, 2)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(3.5500), config), Ok((51, 20))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(3.6000), config), Ok((13, 5)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(3.6500), config), Ok((53, 20))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(3.7000), config), Ok((27, 10))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(3.7500), config), Ok((11, 4)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(3.8000), config), Ok((14, 5)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(3.8500), config), Ok((57, 20))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(3.9500), config), Ok((59, 20))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(4.0000), config), Ok((3, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(4.0500), config), Ok((61, 20))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(4.1000), config), Ok((31, 10)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(4.1500), config), Ok((63, 20))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(4.2000), config), Ok((16, 5)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(4.2500), config), Ok((13, 4))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(4.3000), config), Ok((33, 10))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(4.3300), config), Ok((10, 3)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(4.3500), config), Ok((67, 20))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(4.4000), config), Ok((17, 5)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(4.4500), config), Ok((69, 20))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(4.5000), config), Ok((7, 2)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(4.5500), config), Ok((71, 20))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(4.6000), config), Ok((18, 5)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(4.6500), config), Ok((73, 20))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(4.7000), config), Ok((37, 10))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(4.7500), config), Ok((15, 4)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(4.8000), config), Ok((19, 5)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(4.8500), config), Ok((77, 20))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(4.9000), config), Ok((39, 10))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(4.9500), config), Ok((79, 20))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(5.0000), config), Ok((4, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(5.1000), config), Ok((41, 10))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(5.2000), config), Ok((21, 5))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(5.3000), config), Ok((43, 10))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(5.4000), config), Ok((22, 5))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(5.5000), config), Ok((9, 2)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(5.6000), config), Ok((23, 5))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(5.7000), config), Ok((47, 10))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(5.8000), config), Ok((24, 5))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(5.9000), config), Ok((49, 10))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(6.0000), config), Ok((5, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(6.2000), config), Ok((26, 5))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(6.4000), config), Ok((27, 5))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(6.5000), config), Ok((11, 2)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(6.6000), config), Ok((28, 5))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(6.8000), config), Ok((29, 5))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(7.0000), config), Ok((6, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(7.2000), config), Ok((31, 5))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(7.4000), config), Ok((32, 5))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(7.5000), config), Ok((13, 2)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(7.6000), config), Ok((33, 5))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(7.8000), config), Ok((34, 5))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(8.0000), config), Ok((7, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(8.2000), config), Ok((36, 5))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(8.4000), config), Ok((37, 5))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(8.5000), config), Ok((15, 2)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(8.6000), config), Ok((38, 5))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(8.8000), config), Ok((39, 5))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(9.0000), config), Ok((8, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(9.2000), config), Ok((41, 5))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(9.4000), config), Ok((42, 5))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(9.5000), config), Ok((17, 2)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(9.6000), config), Ok((43, 5))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(9.8000), config), Ok((44, 5))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(10.0000), config), Ok((9, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(11.0000), config), Ok((10, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(12.0000), config), Ok((11, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(13.0000), config), Ok((12, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(14.0000), config), Ok((13, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(15.0000), config), Ok((14, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(16.0000), config), Ok((15, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(17.0000), config), Ok((16, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(19.0000), config), Ok((18, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(21.0000), config), Ok((20, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(23.0000), config), Ok((22, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(26.0000), config), Ok((25, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(29.0000), config), Ok((28, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(31.0000), config), Ok((30, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(34.0000), config), Ok((33, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(36.0000), config), Ok((35, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(41.0000), config), Ok((40, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(46.0000), config), Ok((45, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(51.0000), config), Ok((50, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(56.0000), config), Ok((55, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(61.0000), config), Ok((60, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(67.0000), config), Ok((66, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(71.0000), config), Ok((70, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(76.0000), config), Ok((75, 1))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(81.0000), config), Ok((80, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(86.0000), config), Ok((85, 1))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(91.0000), config), Ok((90, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(96.0000), config), Ok((95, 1))); //disabled
-        assert_eq!(super::decimal_to_fractional_custom(dec!(101.0000), config), Ok((100, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(111.0000), config), Ok((110, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(121.0000), config), Ok((120, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(126.0000), config), Ok((125, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(131.0000), config), Ok((130, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(141.0000), config), Ok((140, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(151.0000), config), Ok((150, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(176.0000), config), Ok((175, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(201.0000), config), Ok((200, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(226.0000), config), Ok((225, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(251.0000), config), Ok((250, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(276.0000), config), Ok((275, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(301.0000), config), Ok((300, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(401.0000), config), Ok((400, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(501.0000), config), Ok((500, 1)));
-        assert_eq!(super::decimal_to_fractional_custom(dec!(1001.0000), config), Ok((1000, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0010), config), Ok(frac(1, 1000)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0013), config), Ok(frac(1, 750)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0020), config), Ok(frac(1, 500)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0025), config), Ok(frac(1, 400)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0030), config), Ok(frac(1, 300)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0040), config), Ok(frac(1, 250)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0050), config), Ok(frac(1, 200)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0070), config), Ok(frac(1, 150)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0100), config), Ok(frac(1, 100)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0120), config), Ok(frac(1, 80)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0150), config), Ok(frac(1, 66)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0200), config), Ok(frac(1, 50)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0250), config), Ok(frac(1, 40)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0300), config), Ok(frac(1, 33)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0400), config), Ok(frac(1, 25)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0500), config), Ok(frac(1, 20)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0550), config), Ok(frac(1, 18)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0600), config), Ok(frac(1, 16)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0700), config), Ok(frac(1, 14)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0800), config), Ok(frac(1, 12)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.0900), config), Ok(frac(1, 11)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.1000), config), Ok(frac(1, 10)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.1100), config), Ok(frac(1, 9)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.1200), config), Ok(frac(1, 8)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.1300), config), Ok(frac(2, 15)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.1400), config), Ok(frac(1, 7)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.1500), config), Ok(frac(2, 13)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.1600), config), Ok(frac(1, 6)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.1800), config), Ok(frac(2, 11))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.1900), config), Ok(frac(19, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.2000), config), Ok(frac(1, 5)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.2100), config), Ok(frac(21, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.2200), config), Ok(frac(2, 9)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.2300), config), Ok(frac(23, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.2400), config), Ok(frac(6, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.2500), config), Ok(frac(1, 4)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.2600), config), Ok(frac(13, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.2700), config), Ok(frac(27, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.2900), config), Ok(frac(2, 7)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.3000), config), Ok(frac(3, 10)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.3100), config), Ok(frac(31, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.3200), config), Ok(frac(8, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.3300), config), Ok(frac(1, 3)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.3400), config), Ok(frac(17, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.3500), config), Ok(frac(7, 20))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.3600), config), Ok(frac(4, 11)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.3700), config), Ok(frac(37, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.3800), config), Ok(frac(19, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.3900), config), Ok(frac(39, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.4000), config), Ok(frac(2, 5)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.4100), config), Ok(frac(41, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.4200), config), Ok(frac(21, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.4300), config), Ok(frac(43, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.4400), config), Ok(frac(4, 9)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.4500), config), Ok(frac(9, 20))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.4600), config), Ok(frac(23, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.4700), config), Ok(frac(40, 85)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.4800), config), Ok(frac(12, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.4900), config), Ok(frac(49, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.5000), config), Ok(frac(1, 2)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.5100), config), Ok(frac(51, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.5200), config), Ok(frac(13, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.5300), config), Ok(frac(8, 15)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.5400), config), Ok(frac(27, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.5500), config), Ok(frac(11, 20))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.5600), config), Ok(frac(14, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.5700), config), Ok(frac(4, 7)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.5800), config), Ok(frac(29, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.5900), config), Ok(frac(59, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.6000), config), Ok(frac(3, 5))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.6100), config), Ok(frac(8, 13)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.6300), config), Ok(frac(63, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.6400), config), Ok(frac(16, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.6500), config), Ok(frac(13, 20))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.6600), config), Ok(frac(4, 6)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.6800), config), Ok(frac(34, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.6900), config), Ok(frac(69, 100)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.7000), config), Ok(frac(7, 10))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.7100), config), Ok(frac(71, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.7200), config), Ok(frac(8, 11)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.7400), config), Ok(frac(37, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.7500), config), Ok(frac(3, 4)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.7600), config), Ok(frac(19, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.7700), config), Ok(frac(77, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.7800), config), Ok(frac(39, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.7900), config), Ok(frac(79, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.8000), config), Ok(frac(4, 5)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.8100), config), Ok(frac(81, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.8200), config), Ok(frac(41, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.8300), config), Ok(frac(5, 6)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.8400), config), Ok(frac(21, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.8500), config), Ok(frac(17, 20))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.8600), config), Ok(frac(20, 23))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.8700), config), Ok(frac(87, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.8800), config), Ok(frac(22, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.8900), config), Ok(frac(89, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.9000), config), Ok(frac(9, 10)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.9100), config), Ok(frac(10, 11)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.9200), config), Ok(frac(23, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.9300), config), Ok(frac(93, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.9400), config), Ok(frac(47, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.9500), config), Ok(frac(20, 21)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.9600), config), Ok(frac(24, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.9700), config), Ok(frac(97, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.9800), config), Ok(frac(49, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1.9900), config), Ok(frac(99, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.0000), config), Ok(frac(1, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.0100), config), Ok(frac(101, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.0200), config), Ok(frac(51, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.0300), config), Ok(frac(103, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.0400), config), Ok(frac(26, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.0500), config), Ok(frac(21, 20)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.0600), config), Ok(frac(53, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.0700), config), Ok(frac(107, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.0800), config), Ok(frac(27, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.0900), config), Ok(frac(109, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.1000), config), Ok(frac(11, 10)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.1100), config), Ok(frac(111, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.1200), config), Ok(frac(28, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.1300), config), Ok(frac(113, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.1400), config), Ok(frac(57, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.1500), config), Ok(frac(23, 20)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.1600), config), Ok(frac(29, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.1700), config), Ok(frac(117, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.1800), config), Ok(frac(59, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.1900), config), Ok(frac(119, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.2000), config), Ok(frac(6, 5)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.2100), config), Ok(frac(121, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.2200), config), Ok(frac(61, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.2300), config), Ok(frac(123, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.2400), config), Ok(frac(31, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.2500), config), Ok(frac(5, 4)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.2600), config), Ok(frac(63, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.2700), config), Ok(frac(127, 100))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.2800), config), Ok(frac(32, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.3000), config), Ok(frac(13, 10)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.3200), config), Ok(frac(33, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.3400), config), Ok(frac(67, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.3500), config), Ok(frac(27, 20))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.3600), config), Ok(frac(34, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.3700), config), Ok(frac(11, 8)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.4000), config), Ok(frac(7, 5)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.4200), config), Ok(frac(71, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.4400), config), Ok(frac(36, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.4500), config), Ok(frac(29, 20)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.4600), config), Ok(frac(73, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.4800), config), Ok(frac(37, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.5000), config), Ok(frac(6, 4)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.5200), config), Ok(frac(38, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.5400), config), Ok(frac(77, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.5600), config), Ok(frac(39, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.5800), config), Ok(frac(79, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.6000), config), Ok(frac(8, 5)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.6200), config), Ok(frac(13, 8)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.6400), config), Ok(frac(41, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.6600), config), Ok(frac(83, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.6800), config), Ok(frac(42, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.7000), config), Ok(frac(17, 10)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.7200), config), Ok(frac(43, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.7400), config), Ok(frac(87, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.7500), config), Ok(frac(7, 4)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.7600), config), Ok(frac(44, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.7800), config), Ok(frac(89, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.8000), config), Ok(frac(9, 5)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.8200), config), Ok(frac(91, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.8400), config), Ok(frac(46, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.8600), config), Ok(frac(93, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.8700), config), Ok(frac(15, 8))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.8800), config), Ok(frac(15, 8))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.9000), config), Ok(frac(19, 10)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.9200), config), Ok(frac(48, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.9400), config), Ok(frac(97, 50)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.9600), config), Ok(frac(49, 25))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(2.9800), config), Ok(frac(99, 50))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(3.0000), config), Ok(frac(2, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(3.0500), config), Ok(frac(41, 20))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(3.1000), config), Ok(frac(21, 10)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(3.1250), config), Ok(frac(85, 40)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(3.1500), config), Ok(frac(43, 20))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(3.2000), config), Ok(frac(11, 5)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(3.2500), config), Ok(frac(9, 4)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(3.3000), config), Ok(frac(23, 10)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(3.3500), config), Ok(frac(47, 20))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(3.4000), config), Ok(frac(12, 5)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(3.4500), config), Ok(frac(49, 20))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(3.5000), config), Ok(frac(5, 2)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(3.5500), config), Ok(frac(51, 20))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(3.6000), config), Ok(frac(13, 5)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(3.6500), config), Ok(frac(53, 20))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(3.7000), config), Ok(frac(27, 10))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(3.7500), config), Ok(frac(11, 4)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(3.8000), config), Ok(frac(14, 5)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(3.8500), config), Ok(frac(57, 20))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(3.9500), config), Ok(frac(59, 20))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(4.0000), config), Ok(frac(3, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(4.0500), config), Ok(frac(61, 20))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(4.1000), config), Ok(frac(31, 10)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(4.1500), config), Ok(frac(63, 20))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(4.2000), config), Ok(frac(16, 5)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(4.2500), config), Ok(frac(13, 4))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(4.3000), config), Ok(frac(33, 10))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(4.3300), config), Ok(frac(10, 3)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(4.3500), config), Ok(frac(67, 20))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(4.4000), config), Ok(frac(17, 5)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(4.4500), config), Ok(frac(69, 20))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(4.5000), config), Ok(frac(7, 2)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(4.5500), config), Ok(frac(71, 20))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(4.6000), config), Ok(frac(18, 5)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(4.6500), config), Ok(frac(73, 20))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(4.7000), config), Ok(frac(37, 10))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(4.7500), config), Ok(frac(15, 4)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(4.8000), config), Ok(frac(19, 5)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(4.8500), config), Ok(frac(77, 20))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(4.9000), config), Ok(frac(39, 10))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(4.9500), config), Ok(frac(79, 20))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(5.0000), config), Ok(frac(4, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(5.1000), config), Ok(frac(41, 10))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(5.2000), config), Ok(frac(21, 5))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(5.3000), config), Ok(frac(43, 10))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(5.4000), config), Ok(frac(22, 5))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(5.5000), config), Ok(frac(9, 2)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(5.6000), config), Ok(frac(23, 5))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(5.7000), config), Ok(frac(47, 10))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(5.8000), config), Ok(frac(24, 5))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(5.9000), config), Ok(frac(49, 10))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(6.0000), config), Ok(frac(5, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(6.2000), config), Ok(frac(26, 5))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(6.4000), config), Ok(frac(27, 5))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(6.5000), config), Ok(frac(11, 2)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(6.6000), config), Ok(frac(28, 5))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(6.8000), config), Ok(frac(29, 5))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(7.0000), config), Ok(frac(6, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(7.2000), config), Ok(frac(31, 5))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(7.4000), config), Ok(frac(32, 5))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(7.5000), config), Ok(frac(13, 2)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(7.6000), config), Ok(frac(33, 5))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(7.8000), config), Ok(frac(34, 5))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(8.0000), config), Ok(frac(7, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(8.2000), config), Ok(frac(36, 5))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(8.4000), config), Ok(frac(37, 5))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(8.5000), config), Ok(frac(15, 2)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(8.6000), config), Ok(frac(38, 5))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(8.8000), config), Ok(frac(39, 5))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(9.0000), config), Ok(frac(8, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(9.2000), config), Ok(frac(41, 5))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(9.4000), config), Ok(frac(42, 5))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(9.5000), config), Ok(frac(17, 2)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(9.6000), config), Ok(frac(43, 5))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(9.8000), config), Ok(frac(44, 5))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(10.0000), config), Ok(frac(9, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(11.0000), config), Ok(frac(10, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(12.0000), config), Ok(frac(11, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(13.0000), config), Ok(frac(12, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(14.0000), config), Ok(frac(13, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(15.0000), config), Ok(frac(14, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(16.0000), config), Ok(frac(15, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(17.0000), config), Ok(frac(16, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(19.0000), config), Ok(frac(18, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(21.0000), config), Ok(frac(20, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(23.0000), config), Ok(frac(22, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(26.0000), config), Ok(frac(25, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(29.0000), config), Ok(frac(28, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(31.0000), config), Ok(frac(30, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(34.0000), config), Ok(frac(33, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(36.0000), config), Ok(frac(35, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(41.0000), config), Ok(frac(40, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(46.0000), config), Ok(frac(45, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(51.0000), config), Ok(frac(50, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(56.0000), config), Ok(frac(55, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(61.0000), config), Ok(frac(60, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(67.0000), config), Ok(frac(66, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(71.0000), config), Ok(frac(70, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(76.0000), config), Ok(frac(75, 1))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(81.0000), config), Ok(frac(80, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(86.0000), config), Ok(frac(85, 1))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(91.0000), config), Ok(frac(90, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(96.0000), config), Ok(frac(95, 1))); //disabled
+        assert_eq!(super::decimal_to_fractional_custom(dec!(101.0000), config), Ok(frac(100, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(111.0000), config), Ok(frac(110, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(121.0000), config), Ok(frac(120, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(126.0000), config), Ok(frac(125, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(131.0000), config), Ok(frac(130, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(141.0000), config), Ok(frac(140, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(151.0000), config), Ok(frac(150, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(176.0000), config), Ok(frac(175, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(201.0000), config), Ok(frac(200, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(226.0000), config), Ok(frac(225, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(251.0000), config), Ok(frac(250, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(276.0000), config), Ok(frac(275, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(301.0000), config), Ok(frac(300, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(401.0000), config), Ok(frac(400, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(501.0000), config), Ok(frac(500, 1)));
+        assert_eq!(super::decimal_to_fractional_custom(dec!(1001.0000), config), Ok(frac(1000, 1)));
     }
 }
@@ -2,6 +2,7 @@ use std::fmt;
 
 /// Represents a race distance in miles, furlongs, and yards.
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RaceDistance {
     pub miles: u32,
     pub furlongs: u32,
@@ -111,4 +112,16 @@ mod tests {
         let converted_dist = RaceDistance::from_yards(2000);
         assert_eq!(format!("{}", converted_dist), "1m 1f 20y");
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() {
+        let distance = RaceDistance::from_yards(2000);
+        let json = serde_json::to_string(&distance).unwrap();
+        assert_eq!(json, r#"{"miles":1,"furlongs":1,"yards":20}"#);
+        assert_eq!(
+            serde_json::from_str::<RaceDistance>(&json).unwrap(),
+            distance
+        );
+    }
 }
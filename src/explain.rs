@@ -0,0 +1,94 @@
+use rust_decimal::Decimal;
+
+use crate::{ConversionConfig, Fraction};
+
+/// How the result of a conversion was produced.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Provenance {
+    /// Found in the basic lookup table.
+    BasicLookup,
+    /// Not found in the basic lookup table, but found in the extended one.
+    ExtendedLookup,
+    /// Found in the custom lookup table.
+    CustomLookup,
+    /// Computed with the continued fraction algorithm.
+    Simplify {
+        /// Convergents computed by the algorithm, the last one is the result.
+        ///
+        /// If the last convergent was rounded in the direction not allowed by config,
+        /// the closest semiconvergent on the allowed side is added as the result.
+        convergents: Vec<Fraction>,
+        /// Absolute difference between the result and the fractional part of the decimal odds.
+        error: Decimal,
+    },
+    /// Computed with the plain fraction strategy.
+    Plain,
+    /// Computed with the preferred denominators strategy.
+    PreferredDenominators,
+    /// Computed directly with the conversion formula (and rounded if needed).
+    Formula,
+}
+
+/// Cheap tag telling how the result was produced, expanded to [`Provenance`] by the explained conversions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Method {
+    BasicLookup,
+    ExtendedLookup,
+    CustomLookup,
+    Simplify,
+    Plain,
+    PreferredDenominators,
+    Formula,
+}
+
+impl Method {
+    /// Explain the result, using the trace if it was computed with the simplify strategy.
+    pub(crate) fn provenance(self, trace: SimplifyTrace) -> Provenance {
+        match self {
+            Method::BasicLookup => Provenance::BasicLookup,
+            Method::ExtendedLookup => Provenance::ExtendedLookup,
+            Method::CustomLookup => Provenance::CustomLookup,
+            Method::Simplify => Provenance::Simplify {
+                convergents: trace.convergents,
+                error: trace.error,
+            },
+            Method::Plain => Provenance::Plain,
+            Method::PreferredDenominators => Provenance::PreferredDenominators,
+            Method::Formula => Provenance::Formula,
+        }
+    }
+}
+
+/// Convergents and error of the simplify strategy, collected only by the explained conversions.
+#[derive(Debug, Default)]
+pub(crate) struct SimplifyTrace {
+    pub(crate) convergents: Vec<Fraction>,
+    pub(crate) error: Decimal,
+}
+
+/// Result of a conversion together with the explanation how it was produced.
+#[derive(Debug, Clone)]
+pub struct ConversionOutcome<'a, T> {
+    pub value: T,
+    pub provenance: Provenance,
+    /// Config used for the conversion.
+    pub config: &'a ConversionConfig,
+}
+
+impl<'a, T> ConversionOutcome<'a, T> {
+    pub(crate) fn new(value: T, provenance: Provenance, config: &'a ConversionConfig) -> Self {
+        Self {
+            value,
+            provenance,
+            config,
+        }
+    }
+
+    /// Check if the value was taken from one of the lookup tables.
+    pub fn is_lookup(&self) -> bool {
+        matches!(
+            self.provenance,
+            Provenance::BasicLookup | Provenance::ExtendedLookup | Provenance::CustomLookup
+        )
+    }
+}
@@ -0,0 +1,310 @@
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+use rust_decimal::Decimal;
+
+use crate::{ConversionError, FractionalOdds, ParseOddsError};
+
+/// Exact non-negative rational number, used for fractional odds.
+///
+/// The fraction is kept in the form it was created with, so traditional prices like 4/6 or 6/4 are
+/// not reduced to 2/3 and 3/2. Equality is structural (4/6 is not equal to 2/3), use
+/// [`Fraction::equivalent`] or [`Fraction::value_cmp`] to compare values.
+/// Results of arithmetic operations are reduced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "FractionRepr", into = "FractionRepr")
+)]
+pub struct Fraction {
+    num: u32,
+    den: u32,
+}
+
+impl Fraction {
+    /// Create fraction, zero denominator is rejected.
+    pub fn new(num: u32, den: u32) -> Result<Self, ConversionError> {
+        if den == 0 {
+            return Err(ConversionError::DenominatorZero { num });
+        }
+        Ok(Self { num, den })
+    }
+
+    /// Create fraction with denominator known to be non-zero (f. ex. from lookup tables).
+    pub(crate) const fn new_unchecked(num: u32, den: u32) -> Self {
+        debug_assert!(den != 0);
+        Self { num, den }
+    }
+
+    pub fn num(&self) -> u32 {
+        self.num
+    }
+
+    pub fn den(&self) -> u32 {
+        self.den
+    }
+
+    /// Fraction in lowest terms, f. ex. 2/3 for 4/6.
+    pub fn reduced(&self) -> Self {
+        let divisor = num_integer::gcd(self.num, self.den);
+        Self {
+            num: self.num / divisor,
+            den: self.den / divisor,
+        }
+    }
+
+    pub fn is_reduced(&self) -> bool {
+        num_integer::gcd(self.num, self.den) == 1
+    }
+
+    /// Exact comparison of values.
+    pub fn value_cmp(&self, other: &Fraction) -> Ordering {
+        (u64::from(self.num) * u64::from(other.den))
+            .cmp(&(u64::from(other.num) * u64::from(self.den)))
+    }
+
+    /// Check if both fractions have the same value, f. ex. 4/6 and 2/3.
+    pub fn equivalent(&self, other: &Fraction) -> bool {
+        self.value_cmp(other) == Ordering::Equal
+    }
+
+    pub fn checked_add(&self, other: &Fraction) -> Option<Fraction> {
+        let (a, b, c, d) = self.wide(other);
+        Self::from_wide(a * d + c * b, b * d)
+    }
+
+    /// Returns `None` if the result would be negative.
+    pub fn checked_sub(&self, other: &Fraction) -> Option<Fraction> {
+        let (a, b, c, d) = self.wide(other);
+        Self::from_wide((a * d).checked_sub(c * b)?, b * d)
+    }
+
+    pub fn checked_mul(&self, other: &Fraction) -> Option<Fraction> {
+        let (a, b, c, d) = self.wide(other);
+        Self::from_wide(a * c, b * d)
+    }
+
+    /// Returns `None` if dividing by zero.
+    pub fn checked_div(&self, other: &Fraction) -> Option<Fraction> {
+        let (a, b, c, d) = self.wide(other);
+        Self::from_wide(a * d, b * c)
+    }
+
+    /// Value of the fraction (not decimal odds, f. ex. 0.5 for 1/2).
+    pub fn to_decimal(&self) -> Decimal {
+        Decimal::from(self.num) / Decimal::from(self.den)
+    }
+
+    fn wide(&self, other: &Fraction) -> (u128, u128, u128, u128) {
+        (
+            u128::from(self.num),
+            u128::from(self.den),
+            u128::from(other.num),
+            u128::from(other.den),
+        )
+    }
+
+    /// Reduce and narrow the result of arithmetic, `None` on overflow or zero denominator.
+    fn from_wide(num: u128, den: u128) -> Option<Fraction> {
+        if den == 0 {
+            return None;
+        }
+
+        let divisor = num_integer::gcd(num, den);
+        Some(Self {
+            num: u32::try_from(num / divisor).ok()?,
+            den: u32::try_from(den / divisor).ok()?,
+        })
+    }
+}
+
+impl TryFrom<(u32, u32)> for Fraction {
+    type Error = ConversionError;
+
+    fn try_from((num, den): (u32, u32)) -> Result<Self, Self::Error> {
+        Self::new(num, den)
+    }
+}
+
+impl From<Fraction> for (u32, u32) {
+    fn from(value: Fraction) -> Self {
+        (value.num, value.den)
+    }
+}
+
+/// Structural comparison with `(num, den)` tuple.
+impl PartialEq<(u32, u32)> for Fraction {
+    fn eq(&self, (num, den): &(u32, u32)) -> bool {
+        self.num == *num && self.den == *den
+    }
+}
+
+impl From<FractionalOdds> for Fraction {
+    fn from(value: FractionalOdds) -> Self {
+        Self::new_unchecked(value.num(), value.den())
+    }
+}
+
+impl TryFrom<Fraction> for FractionalOdds {
+    type Error = ConversionError;
+
+    fn try_from(value: Fraction) -> Result<Self, Self::Error> {
+        Self::new(value.num, value.den)
+    }
+}
+
+/// Exact conversion, reduced (f. ex. 0.25 gives 1/4).
+impl TryFrom<Decimal> for Fraction {
+    type Error = ConversionError;
+
+    fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+        if value.is_sign_negative() && !value.is_zero() {
+            return Err(ConversionError::NegativeFraction(value));
+        }
+
+        // Scale is at most 28, so the power fits in u128
+        let num = value.mantissa().unsigned_abs();
+        let den = 10u128.pow(value.scale());
+
+        Self::from_wide(num, den).ok_or(ConversionError::FractionOverflow(value))
+    }
+}
+
+impl From<Fraction> for Decimal {
+    fn from(value: Fraction) -> Self {
+        value.to_decimal()
+    }
+}
+
+impl fmt::Display for Fraction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.num, self.den)
+    }
+}
+
+impl FromStr for Fraction {
+    type Err = ParseOddsError;
+
+    /// Parse `n/d` fraction, zero denominator is rejected.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (num, den) = s.split_once('/').ok_or(ParseOddsError::InvalidFormat)?;
+        let num = num
+            .trim()
+            .parse()
+            .map_err(|_| ParseOddsError::InvalidFormat)?;
+        let den = den
+            .trim()
+            .parse()
+            .map_err(|_| ParseOddsError::InvalidFormat)?;
+
+        Self::new(num, den).map_err(ParseOddsError::InvalidOdds)
+    }
+}
+
+/// Serde form of [`Fraction`] and [`FractionalOdds`].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub(crate) struct FractionRepr {
+    pub(crate) num: u32,
+    pub(crate) den: u32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<FractionRepr> for Fraction {
+    type Error = ConversionError;
+
+    fn try_from(value: FractionRepr) -> Result<Self, Self::Error> {
+        Self::new(value.num, value.den)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Fraction> for FractionRepr {
+    fn from(value: Fraction) -> Self {
+        Self {
+            num: value.num,
+            den: value.den,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use crate::testing_helpers::frac;
+
+    use super::*;
+
+    #[test]
+    fn test_traditional_form() {
+        let fraction = frac(4, 6);
+
+        assert_eq!(fraction.to_string(), "4/6");
+        assert!(!fraction.is_reduced());
+        assert_eq!(fraction.reduced(), frac(2, 3));
+        assert!(frac(2, 3).is_reduced());
+
+        // Structural equality keeps traditional form, values can be compared separately
+        assert_ne!(fraction, frac(2, 3));
+        assert!(fraction.equivalent(&frac(2, 3)));
+        assert_eq!(frac(6, 4).value_cmp(&frac(4, 6)), Ordering::Greater);
+
+        assert_eq!(
+            Fraction::new(1, 0),
+            Err(ConversionError::DenominatorZero { num: 1 })
+        );
+        assert_eq!(<(u32, u32)>::from(fraction), (4, 6));
+        assert_eq!(fraction, (4, 6));
+        assert_ne!(fraction, (2, 3));
+        assert_eq!(Fraction::try_from((6, 4)), Ok(frac(6, 4)));
+
+        assert_eq!("4/6".parse(), Ok(fraction));
+        assert_eq!(" 85 / 40 ".parse(), Ok(frac(85, 40)));
+        assert_eq!("4".parse::<Fraction>(), Err(ParseOddsError::InvalidFormat));
+        assert_eq!(
+            "4/0".parse::<Fraction>(),
+            Err(ParseOddsError::InvalidOdds(
+                ConversionError::DenominatorZero { num: 4 }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        assert_eq!(frac(1, 2).checked_add(&frac(1, 3)), Some(frac(5, 6)));
+        assert_eq!(frac(4, 6).checked_add(&frac(1, 3)), Some(frac(1, 1)));
+        assert_eq!(frac(1, 2).checked_sub(&frac(1, 3)), Some(frac(1, 6)));
+        assert_eq!(frac(1, 3).checked_sub(&frac(1, 2)), None);
+        assert_eq!(frac(5, 2).checked_mul(&frac(4, 5)), Some(frac(2, 1)));
+        assert_eq!(frac(5, 2).checked_div(&frac(5, 4)), Some(frac(2, 1)));
+        assert_eq!(frac(5, 2).checked_div(&frac(0, 1)), None);
+
+        // Overflow
+        assert_eq!(frac(u32::MAX, 1).checked_add(&frac(1, 1)), None);
+        assert_eq!(frac(1, u32::MAX).checked_mul(&frac(1, 2)), None);
+        // Reduced result fits
+        assert_eq!(
+            frac(u32::MAX, 2).checked_mul(&frac(2, u32::MAX)),
+            Some(frac(1, 1))
+        );
+    }
+
+    #[test]
+    fn test_decimal_conversions() {
+        assert_eq!(frac(1, 4).to_decimal(), dec!(0.25));
+        assert_eq!(Decimal::from(frac(5, 2)), dec!(2.5));
+
+        assert_eq!(Fraction::try_from(dec!(0.25)), Ok(frac(1, 4)));
+        assert_eq!(Fraction::try_from(dec!(2.50)), Ok(frac(5, 2)));
+        assert_eq!(Fraction::try_from(dec!(0)), Ok(frac(0, 1)));
+        assert_eq!(
+            Fraction::try_from(dec!(-0.5)),
+            Err(ConversionError::NegativeFraction(dec!(-0.5)))
+        );
+        assert_eq!(
+            Fraction::try_from(dec!(0.0000000001)),
+            Err(ConversionError::FractionOverflow(dec!(0.0000000001)))
+        );
+    }
+}
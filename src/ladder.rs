@@ -0,0 +1,310 @@
+use rust_decimal::Decimal;
+
+use crate::{
+    ConversionConfig, Fraction, LookupEntry, LookupTable, LookupVariant, Odds,
+    decimal_to_american_custom,
+    lookup_tables::{
+        StaticTable, get_decimal_to_american_extended_map, get_decimal_to_american_map,
+        get_decimal_to_fraction_extended_map, get_decimal_to_fraction_map,
+    },
+};
+
+/// Prices of lookup tables in price order, from the shortest (f. ex. for price pickers).
+///
+/// Each price has all formats. Prices of the decimal to fractional tables which are not in
+/// the american tables get american odds computed without lookup.
+///
+/// Odds passed to [`Ladder::next_price`], [`Ladder::prev_price`] and [`Ladder::range`] are compared
+/// with the prices in the same format, so the price after 4/6 is 8/11, while the price after 1.67 is 1.73.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Ladder {
+    entries: Vec<LookupEntry>,
+}
+
+impl Ladder {
+    /// Ladder of the tables used with given variant, empty for [`LookupVariant::None`].
+    pub fn new(variant: &LookupVariant) -> Self {
+        match variant {
+            LookupVariant::None => Self::default(),
+            LookupVariant::Basic => Self::from_tables(
+                &[get_decimal_to_fraction_map()],
+                &[get_decimal_to_american_map()],
+            ),
+            LookupVariant::Extended => Self::from_tables(
+                &[
+                    get_decimal_to_fraction_map(),
+                    get_decimal_to_fraction_extended_map(),
+                ],
+                &[
+                    get_decimal_to_american_map(),
+                    get_decimal_to_american_extended_map(),
+                ],
+            ),
+            LookupVariant::Custom(table) => Self::custom(table),
+        }
+    }
+
+    pub fn basic() -> Self {
+        Self::new(&LookupVariant::Basic)
+    }
+
+    pub fn extended() -> Self {
+        Self::new(&LookupVariant::Extended)
+    }
+
+    /// Ladder of a custom table, with the prices used by conversions.
+    pub fn custom(table: &LookupTable) -> Self {
+        Self::sorted(table.entries().to_vec())
+    }
+
+    /// Earlier tables take precedence.
+    fn from_tables(
+        fractions: &[&StaticTable<Decimal, (u32, u32)>],
+        americans: &[&StaticTable<Decimal, i32>],
+    ) -> Self {
+        let config = ConversionConfig::default().no_lookup();
+        Self::sorted(
+            fractions
+                .iter()
+                .flat_map(|table| table.iter())
+                .filter_map(|(decimal, (num, den))| {
+                    let american = match americans.iter().find_map(|table| table.get(&decimal)) {
+                        Some(american) => *american,
+                        None => decimal_to_american_custom(decimal, &config).ok()?,
+                    };
+                    Some(LookupEntry {
+                        decimal,
+                        american,
+                        fraction: Fraction::new_unchecked(*num, *den),
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    fn sorted(mut entries: Vec<LookupEntry>) -> Self {
+        // Stable sort, so the first of equal prices is kept
+        entries.sort_by_key(|entry| entry.decimal);
+        entries.dedup_by_key(|entry| entry.decimal);
+        Self { entries }
+    }
+
+    /// Prices from the shortest.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &LookupEntry> {
+        self.entries.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The shortest price which is longer than given odds.
+    pub fn next_price(&self, odds: &Odds) -> Option<&LookupEntry> {
+        self.entries
+            .iter()
+            .find(|entry| price_like(entry, odds).is_some_and(|price| price < *odds))
+    }
+
+    /// The longest price which is shorter than given odds.
+    pub fn prev_price(&self, odds: &Odds) -> Option<&LookupEntry> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|entry| price_like(entry, odds).is_some_and(|price| price > *odds))
+    }
+
+    /// Prices between given odds (inclusive, in any order), from the shortest.
+    pub fn range(&self, from: &Odds, to: &Odds) -> impl DoubleEndedIterator<Item = &LookupEntry> {
+        // Longer odds are less
+        let (longest, shortest) = if from < to {
+            (*from, *to)
+        } else {
+            (*to, *from)
+        };
+        self.entries.iter().filter(move |entry| {
+            price_like(entry, &longest).is_some_and(|price| price >= longest)
+                && price_like(entry, &shortest).is_some_and(|price| price <= shortest)
+        })
+    }
+}
+
+impl<'a> IntoIterator for &'a Ladder {
+    type Item = &'a LookupEntry;
+    type IntoIter = std::slice::Iter<'a, LookupEntry>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter()
+    }
+}
+
+/// Price of the entry in the format of given odds.
+fn price_like(entry: &LookupEntry, odds: &Odds) -> Option<Odds> {
+    match odds {
+        Odds::American(_) | Odds::PreciseAmerican(_) => Odds::american(entry.american),
+        Odds::Decimal(_) => Odds::decimal(entry.decimal),
+        Odds::Fractional(_) => Odds::fractional(entry.fraction.num(), entry.fraction.den()),
+    }
+    .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use crate::testing_helpers::frac;
+
+    use super::*;
+
+    fn fractional(num: u32, den: u32) -> Odds {
+        Odds::fractional(num, den).unwrap()
+    }
+
+    #[test]
+    fn test_basic_ladder() {
+        let ladder = Ladder::basic();
+        assert_eq!(ladder.len(), 69);
+        assert!(
+            ladder
+                .iter()
+                .zip(ladder.iter().skip(1))
+                .all(|(a, b)| a.decimal < b.decimal && a.fraction.value_cmp(&b.fraction).is_lt())
+        );
+
+        let first = ladder.iter().next().unwrap();
+        assert_eq!(
+            *first,
+            LookupEntry {
+                decimal: dec!(1.01),
+                american: -10000,
+                fraction: frac(1, 100),
+            }
+        );
+        assert_eq!(ladder.iter().next_back().unwrap().fraction, frac(1000, 1));
+
+        // Compared in the same format
+        let next = ladder.next_price(&fractional(4, 6)).unwrap();
+        assert_eq!(next.fraction, frac(8, 11));
+        assert_eq!(ladder.next_price(&fractional(2, 3)), Some(next));
+        let next = ladder
+            .next_price(&Odds::decimal(dec!(1.67)).unwrap())
+            .unwrap();
+        assert_eq!(next.decimal, dec!(1.73));
+        let next = ladder
+            .next_price(&Odds::decimal(dec!(1.7)).unwrap())
+            .unwrap();
+        assert_eq!(next.decimal, dec!(1.73));
+        let prev = ladder.prev_price(&fractional(4, 6)).unwrap();
+        assert_eq!(prev.fraction, frac(8, 13));
+        let next = ladder.next_price(&Odds::american(-110).unwrap()).unwrap();
+        assert_eq!(next.american, 100);
+        let prev = ladder
+            .prev_price(&Odds::precise_american(dec!(112.5)).unwrap())
+            .unwrap();
+        assert_eq!(prev.american, 110);
+
+        assert_eq!(ladder.prev_price(&fractional(1, 100)), None);
+        assert_eq!(ladder.next_price(&fractional(1000, 1)), None);
+        assert_eq!(
+            ladder.next_price(&fractional(2000, 1)),
+            None,
+            "longer than the whole ladder"
+        );
+        assert_eq!(
+            ladder.prev_price(&fractional(2000, 1)).unwrap().fraction,
+            frac(1000, 1)
+        );
+    }
+
+    #[test]
+    fn test_range() {
+        let ladder = Ladder::basic();
+
+        let prices: Vec<_> = ladder
+            .range(&fractional(1, 5), &fractional(50, 1))
+            .collect();
+        assert_eq!(prices.first().unwrap().fraction, frac(1, 5));
+        assert_eq!(prices.last().unwrap().fraction, frac(50, 1));
+        // 1/100 and 66/1 to 1000/1 are outside
+        assert_eq!(prices.len(), ladder.len() - 4);
+
+        // Bounds in any order and format
+        let reversed: Vec<_> = ladder
+            .range(
+                &Odds::american(5000).unwrap(),
+                &Odds::decimal(dec!(1.2)).unwrap(),
+            )
+            .collect();
+        assert_eq!(reversed, prices);
+
+        let evens: Vec<_> = ladder
+            .range(&Odds::decimal(dec!(1.95)).unwrap(), &fractional(21, 20))
+            .map(|entry| entry.fraction)
+            .collect();
+        assert_eq!(evens, [frac(1, 1), frac(21, 20)]);
+
+        assert_eq!(
+            ladder.range(&fractional(1, 1), &fractional(1, 1)).count(),
+            1
+        );
+        assert_eq!(
+            ladder
+                .range(&fractional(17, 8), &fractional(21, 10))
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_extended_and_custom_ladder() {
+        let ladder = Ladder::extended();
+        assert!(ladder.len() > Ladder::basic().len());
+        assert!(
+            ladder
+                .iter()
+                .zip(ladder.iter().skip(1))
+                .all(|(a, b)| a.decimal < b.decimal)
+        );
+        assert!(
+            Ladder::basic()
+                .iter()
+                .all(|entry| ladder.iter().any(|e| e == entry))
+        );
+
+        let entry = ladder
+            .iter()
+            .find(|entry| entry.decimal == dec!(1.0013))
+            .unwrap();
+        assert_eq!(entry.fraction, frac(1, 750));
+        assert_eq!(entry.american, -76923);
+
+        // 2.87 and 2.88 are both 15/8
+        let next = ladder.next_price(&fractional(15, 8)).unwrap();
+        assert!(next.fraction.value_cmp(&frac(15, 8)).is_gt());
+
+        let table = LookupTable::from_csv(
+            "
+            3.75,+275,11/4
+            1.91,-110,10/11
+            1.8,-125,4/5
+            1.8,-120,5/6
+            ",
+        )
+        .unwrap();
+        let ladder = Ladder::new(&LookupVariant::Custom(table.clone().into()));
+        assert_eq!(ladder, Ladder::custom(&table));
+        let prices: Vec<_> = ladder
+            .iter()
+            .map(|entry| (entry.american, entry.fraction))
+            .collect();
+        assert_eq!(
+            prices,
+            [(-120, frac(5, 6)), (-110, frac(10, 11)), (275, frac(11, 4))]
+        );
+
+        assert!(Ladder::new(&LookupVariant::None).is_empty());
+    }
+}
@@ -1,5 +1,17 @@
 #![doc = pretty_readme::docify!("README.md", "https://docs.rs/oddsidizer/latest/oddsidizer/", "./")]
 
+mod analytics;
+pub use analytics::*;
+
+mod boost;
+pub use boost::*;
+
+mod cash_out;
+pub use cash_out::*;
+
+#[cfg(feature = "serde")]
+pub mod compact_odds;
+
 mod config;
 pub use config::*;
 
@@ -9,13 +21,46 @@ pub use convert::*;
 mod distance;
 pub use distance::RaceDistance;
 
+mod explain;
+pub use explain::*;
+
+mod fraction;
+pub use fraction::*;
+
+mod ladder;
+pub use ladder::*;
+
+mod lookup_table;
+pub use lookup_table::*;
+
 mod lookup_tables;
 
 mod lookup_funcs;
 pub use lookup_funcs::*;
 
+mod market;
+pub use market::*;
+
+mod matched_betting;
+pub use matched_betting::*;
+
 mod odds;
 pub use odds::*;
 
+mod registry;
+pub use registry::*;
+
+mod scoring;
+pub use scoring::*;
+
 #[cfg(test)]
 mod testing_helpers;
+
+mod table_generator;
+pub use table_generator::*;
+
+mod table_validation;
+pub use table_validation::*;
+
+mod validated;
+pub use validated::*;
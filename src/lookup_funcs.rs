@@ -1,47 +1,177 @@
+use std::cmp::Ordering;
+
 use rust_decimal::Decimal;
 
 use crate::{
     ConversionConfig, LookupVariant,
+    convert::is_rounding_allowed,
     lookup_tables::{
         get_american_to_fraction_extended_map, get_american_to_fraction_map,
         get_decimal_to_fraction_extended_map, get_decimal_to_fraction_map,
     },
+    odds::{american_ratio, cmp_ratios, decimal_ratio, fraction_ratio},
+    registry::with_default_config,
 };
 
 /// Manually Lookup decimal to fractional table using provided config
+///
+/// Odds are normalised with `decimal_normalization` first, as by the conversion functions.
+/// Fractions rounded in the direction not allowed by `price_rounding` are skipped.
 pub fn lookup_decimal_to_fraction_with_config(
     odds: Decimal,
-    config: ConversionConfig,
+    config: &ConversionConfig,
 ) -> Option<(u32, u32)> {
-    let frac = get_decimal_to_fraction_map().get(&odds);
+    let odds = config.normalize_decimal(odds);
+    let allowed = |(num, den): &&(u32, u32)| {
+        is_rounding_allowed(config, fraction_ratio(*num, *den), decimal_ratio(odds))
+    };
+    if let LookupVariant::Custom(table) = &config.lookup_tables_variant {
+        return table
+            .decimal_to_fraction_map()
+            .get(&odds)
+            .filter(allowed)
+            .copied();
+    }
+
+    let frac = get_decimal_to_fraction_map().get(&odds).filter(allowed);
 
     if frac.is_none() && config.lookup_tables_variant == LookupVariant::Extended {
-        return get_decimal_to_fraction_extended_map().get(&odds).copied();
+        return get_decimal_to_fraction_extended_map()
+            .get(&odds)
+            .filter(allowed)
+            .copied();
     }
 
     frac.copied()
 }
 
-/// Manually Lookup decimal to fractional table using default config (no extended tables used)
+/// Manually Lookup decimal to fractional table using default config (no extended tables used, unless the global default is set)
 pub fn lookup_decimal_to_fraction(odds: Decimal) -> Option<(u32, u32)> {
-    lookup_decimal_to_fraction_with_config(odds, ConversionConfig::default())
+    with_default_config(|config| lookup_decimal_to_fraction_with_config(odds, config))
 }
 
 /// Manually Lookup american to fractional table using provided config
+///
+/// Fractions rounded in the direction not allowed by `price_rounding` are skipped.
 pub fn lookup_american_to_fraction_with_config(
     odds: i32,
-    config: ConversionConfig,
+    config: &ConversionConfig,
 ) -> Option<(u32, u32)> {
-    let frac = get_american_to_fraction_map().get(&odds);
+    let allowed = |(num, den): &&(u32, u32)| {
+        is_rounding_allowed(
+            config,
+            fraction_ratio(*num, *den),
+            american_ratio(Decimal::from(odds)),
+        )
+    };
+    if let LookupVariant::Custom(table) = &config.lookup_tables_variant {
+        return table
+            .american_to_fraction_map()
+            .get(&odds)
+            .filter(allowed)
+            .copied();
+    }
+
+    let frac = get_american_to_fraction_map().get(&odds).filter(allowed);
 
     if frac.is_none() && config.lookup_tables_variant == LookupVariant::Extended {
-        return get_american_to_fraction_extended_map().get(&odds).copied();
+        return get_american_to_fraction_extended_map()
+            .get(&odds)
+            .filter(allowed)
+            .copied();
     }
 
     frac.copied()
 }
 
-/// Manually Lookup american to fractional table using default config (no extended tables used)
+/// Manually Lookup american to fractional table using default config (no extended tables used, unless the global default is set)
 pub fn lookup_american_to_fraction(odds: i32) -> Option<(u32, u32)> {
-    lookup_american_to_fraction_with_config(odds, ConversionConfig::default())
+    with_default_config(|config| lookup_american_to_fraction_with_config(odds, config))
+}
+
+/// Find the longest fraction from lookup tables which is not longer than given decimal odds.
+///
+/// Used to snap computed prices to traditional fractions without overpaying.
+/// Odds are normalised with `decimal_normalization` first, as by the conversion functions.
+pub fn lookup_fraction_at_or_below_with_config(
+    odds: Decimal,
+    config: &ConversionConfig,
+) -> Option<(u32, u32)> {
+    let odds = config.normalize_decimal(odds);
+    if odds.is_sign_negative() {
+        return None;
+    }
+    lookup_fraction_at_or_below_ratio(decimal_ratio(odds), config)
+}
+
+/// Find the longest fraction from lookup tables which is not longer than the exact decimal price `(num, den)`.
+pub(crate) fn lookup_fraction_at_or_below_ratio(
+    limit: (u128, u128),
+    config: &ConversionConfig,
+) -> Option<(u32, u32)> {
+    match &config.lookup_tables_variant {
+        LookupVariant::None => None,
+        LookupVariant::Basic => longest_at_or_below(get_decimal_to_fraction_map().values(), limit),
+        LookupVariant::Extended => longest_at_or_below(
+            get_decimal_to_fraction_map()
+                .values()
+                .chain(get_decimal_to_fraction_extended_map().values()),
+            limit,
+        ),
+        LookupVariant::Custom(table) => {
+            longest_at_or_below(table.decimal_to_fraction_map().values(), limit)
+        }
+    }
+}
+
+fn longest_at_or_below<'a>(
+    fractions: impl Iterator<Item = &'a (u32, u32)>,
+    limit: (u128, u128),
+) -> Option<(u32, u32)> {
+    fractions
+        .filter(|(num, den)| cmp_ratios(fraction_ratio(*num, *den), limit) != Ordering::Greater)
+        // Compare by value, equal values prefer the traditional (unreduced) form, f. ex. 4/6 over 2/3
+        .max_by(|(a_num, a_den), (b_num, b_den)| {
+            cmp_ratios(
+                fraction_ratio(*a_num, *a_den),
+                fraction_ratio(*b_num, *b_den),
+            )
+            .then(a_den.cmp(b_den))
+        })
+        .copied()
+}
+
+/// Find the longest fraction from lookup tables which is not longer than given decimal odds using default config.
+pub fn lookup_fraction_at_or_below(odds: Decimal) -> Option<(u32, u32)> {
+    with_default_config(|config| lookup_fraction_at_or_below_with_config(odds, config))
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_fraction_at_or_below() {
+        assert_eq!(lookup_fraction_at_or_below(dec!(1.5)), Some((1, 2)));
+        assert_eq!(lookup_fraction_at_or_below(dec!(1.67)), Some((4, 6)));
+        // 4/6 is 1.666..., equal values prefer the traditional form
+        assert_eq!(lookup_fraction_at_or_below(dec!(1.6667)), Some((4, 6)));
+
+        // Just below 4/3, so 1/3 is longer
+        assert_eq!(
+            lookup_fraction_at_or_below(dec!(1.3333333333333333333333333333)),
+            Some((3, 10))
+        );
+        assert_eq!(lookup_fraction_at_or_below(dec!(1.01)), Some((1, 100)));
+        assert_eq!(lookup_fraction_at_or_below(dec!(1.0099)), None);
+        assert_eq!(
+            lookup_fraction_at_or_below_with_config(
+                dec!(5),
+                &ConversionConfig::default().no_lookup()
+            ),
+            None
+        );
+    }
 }
@@ -0,0 +1,528 @@
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use rust_decimal::Decimal;
+
+use crate::{AmericanOdds, ConversionError, DecimalOdds, Fraction, FractionalOdds, ParseOddsError};
+
+/// Single price of a price board in all formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LookupEntry {
+    pub decimal: Decimal,
+    pub american: i32,
+    /// (De)serialized as `"n/d"` string.
+    #[cfg_attr(feature = "serde", serde(with = "fraction_string"))]
+    pub fraction: Fraction,
+}
+
+/// Lookup table with prices of a custom price board, used by converters instead of the built-in tables
+/// with [`LookupVariant::Custom`](crate::LookupVariant::Custom).
+///
+/// Entries are validated when inserted. Later entries replace earlier ones with the same decimal, american
+/// or fractional price (fractions are matched as written, so 4/6 and 2/3 are different keys), so every price
+/// is listed once.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "LookupTableRepr", into = "LookupTableRepr")
+)]
+pub struct LookupTable {
+    entries: Vec<LookupEntry>,
+    decimal_to_fraction: HashMap<Decimal, (u32, u32)>,
+    american_to_fraction: HashMap<i32, (u32, u32)>,
+    american_to_decimal: HashMap<i32, Decimal>,
+    fraction_to_decimal: HashMap<(u32, u32), Decimal>,
+    fraction_to_american: HashMap<(u32, u32), i32>,
+    decimal_to_american: HashMap<Decimal, i32>,
+}
+
+impl LookupTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build table from entries, failing on the first invalid one.
+    pub fn from_entries(
+        entries: impl IntoIterator<Item = LookupEntry>,
+    ) -> Result<Self, ConversionError> {
+        let mut table = Self::new();
+        for entry in entries {
+            table.insert(entry)?;
+        }
+        Ok(table)
+    }
+
+    /// Parse table from CSV with `decimal,american,fraction` columns, f. ex. `1.91,-110,10/11`.
+    ///
+    /// Empty lines, lines starting with `#` and a header line starting with `decimal` are skipped.
+    pub fn from_csv(csv: &str) -> Result<Self, ParseLookupTableError> {
+        let mut table = Self::new();
+
+        for (index, line) in csv.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with("decimal") {
+                continue;
+            }
+
+            let error = |error| ParseLookupTableError {
+                line: index + 1,
+                error,
+            };
+            let entry = parse_csv_entry(line).map_err(error)?;
+            table
+                .insert(entry)
+                .map_err(|err| error(ParseOddsError::InvalidOdds(err)))?;
+        }
+
+        Ok(table)
+    }
+
+    /// Write table as CSV with a header, in the order of insertion.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("decimal,american,fraction\n");
+        for entry in &self.entries {
+            csv.push_str(&format!(
+                "{},{},{}\n",
+                entry.decimal, entry.american, entry.fraction
+            ));
+        }
+        csv
+    }
+
+    /// Add validated entry to the table.
+    ///
+    /// Entries with the same decimal, american or fractional price are removed, the new one takes the place
+    /// of the first of them.
+    pub fn insert(&mut self, entry: LookupEntry) -> Result<(), ConversionError> {
+        DecimalOdds::new(entry.decimal)?;
+        AmericanOdds::new(entry.american)?;
+        FractionalOdds::new(entry.fraction.num(), entry.fraction.den())?;
+
+        let conflicts = |other: &LookupEntry| {
+            other.decimal == entry.decimal
+                || other.american == entry.american
+                || other.fraction == entry.fraction
+        };
+        let position = self.entries.iter().position(conflicts);
+        let replaced: Vec<_> = self
+            .entries
+            .extract_if(.., |other| conflicts(other))
+            .collect();
+        for old in &replaced {
+            self.remove_mappings(old);
+        }
+
+        let fraction = entry.fraction.into();
+        self.decimal_to_fraction.insert(entry.decimal, fraction);
+        self.american_to_fraction.insert(entry.american, fraction);
+        self.american_to_decimal
+            .insert(entry.american, entry.decimal);
+        self.fraction_to_decimal.insert(fraction, entry.decimal);
+        self.fraction_to_american.insert(fraction, entry.american);
+        self.decimal_to_american
+            .insert(entry.decimal, entry.american);
+        match position {
+            Some(position) => self.entries.insert(position, entry),
+            None => self.entries.push(entry),
+        }
+        Ok(())
+    }
+
+    // Every price is in a single entry, so its keys can be dropped from all maps
+    fn remove_mappings(&mut self, entry: &LookupEntry) {
+        let fraction = entry.fraction.into();
+        self.decimal_to_fraction.remove(&entry.decimal);
+        self.american_to_fraction.remove(&entry.american);
+        self.american_to_decimal.remove(&entry.american);
+        self.fraction_to_decimal.remove(&fraction);
+        self.fraction_to_american.remove(&fraction);
+        self.decimal_to_american.remove(&entry.decimal);
+    }
+
+    /// Entries in the order of insertion.
+    pub fn entries(&self) -> &[LookupEntry] {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn decimal_to_fraction(&self, decimal: Decimal) -> Option<Fraction> {
+        self.decimal_to_fraction
+            .get(&decimal)
+            .map(|(num, den)| Fraction::new_unchecked(*num, *den))
+    }
+
+    pub fn american_to_fraction(&self, american: i32) -> Option<Fraction> {
+        self.american_to_fraction
+            .get(&american)
+            .map(|(num, den)| Fraction::new_unchecked(*num, *den))
+    }
+
+    pub fn american_to_decimal(&self, american: i32) -> Option<Decimal> {
+        self.american_to_decimal.get(&american).copied()
+    }
+
+    pub fn fraction_to_decimal(&self, fraction: Fraction) -> Option<Decimal> {
+        self.fraction_to_decimal
+            .get(&(fraction.num(), fraction.den()))
+            .copied()
+    }
+
+    pub fn fraction_to_american(&self, fraction: Fraction) -> Option<i32> {
+        self.fraction_to_american
+            .get(&(fraction.num(), fraction.den()))
+            .copied()
+    }
+
+    pub fn decimal_to_american(&self, decimal: Decimal) -> Option<i32> {
+        self.decimal_to_american.get(&decimal).copied()
+    }
+
+    pub(crate) fn decimal_to_fraction_map(&self) -> &HashMap<Decimal, (u32, u32)> {
+        &self.decimal_to_fraction
+    }
+
+    pub(crate) fn american_to_fraction_map(&self) -> &HashMap<i32, (u32, u32)> {
+        &self.american_to_fraction
+    }
+
+    pub(crate) fn american_to_decimal_map(&self) -> &HashMap<i32, Decimal> {
+        &self.american_to_decimal
+    }
+
+    pub(crate) fn fraction_to_decimal_map(&self) -> &HashMap<(u32, u32), Decimal> {
+        &self.fraction_to_decimal
+    }
+
+    pub(crate) fn fraction_to_american_map(&self) -> &HashMap<(u32, u32), i32> {
+        &self.fraction_to_american
+    }
+
+    pub(crate) fn decimal_to_american_map(&self) -> &HashMap<Decimal, i32> {
+        &self.decimal_to_american
+    }
+}
+
+fn parse_csv_entry(line: &str) -> Result<LookupEntry, ParseOddsError> {
+    let mut columns = line.split(',').map(str::trim);
+    let (Some(decimal), Some(american), Some(fraction), None) = (
+        columns.next(),
+        columns.next(),
+        columns.next(),
+        columns.next(),
+    ) else {
+        return Err(ParseOddsError::InvalidFormat);
+    };
+
+    Ok(LookupEntry {
+        decimal: decimal.parse().map_err(|_| ParseOddsError::InvalidFormat)?,
+        american: american
+            .trim_start_matches('+')
+            .parse()
+            .map_err(|_| ParseOddsError::InvalidFormat)?,
+        fraction: fraction.parse()?,
+    })
+}
+
+/// Error of parsing lookup table from CSV, with 1-based line number.
+#[derive(Debug, PartialEq)]
+pub struct ParseLookupTableError {
+    pub line: usize,
+    pub error: ParseOddsError,
+}
+
+impl fmt::Display for ParseLookupTableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.error)
+    }
+}
+
+impl std::error::Error for ParseLookupTableError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl FromStr for LookupTable {
+    type Err = ParseLookupTableError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_csv(s)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct LookupTableRepr {
+    entries: Vec<LookupEntry>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<LookupTableRepr> for LookupTable {
+    type Error = ConversionError;
+
+    fn try_from(value: LookupTableRepr) -> Result<Self, Self::Error> {
+        Self::from_entries(value.entries)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<LookupTable> for LookupTableRepr {
+    fn from(value: LookupTable) -> Self {
+        Self {
+            entries: value.entries,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod fraction_string {
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    use crate::Fraction;
+
+    pub fn serialize<S: Serializer>(fraction: &Fraction, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(fraction)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Fraction, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse()
+            .map_err(|err| D::Error::custom(format!("{err}: {s}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use rust_decimal_macros::dec;
+
+    use crate::{
+        ConversionConfig, LookupVariant, PriceRounding, Provenance, american_to_decimal_custom,
+        american_to_fractional_custom, decimal_to_american_custom, decimal_to_fractional_explained,
+        fractional_to_american_custom, fractional_to_decimal_custom,
+        fractional_to_decimal_explained, lookup_decimal_to_fraction_with_config,
+        lookup_fraction_at_or_below_with_config, testing_helpers::frac,
+    };
+
+    use super::*;
+
+    const BOARD: &str = "
+        decimal,american,fraction
+        # Odds-on
+        1.91,-110,10/11
+        1.8,-125,4/5
+        # Odds-against
+        3.75,+275,11/4
+        3.125,+213,85/40
+    ";
+
+    #[test]
+    fn test_build_table() {
+        let mut table = LookupTable::new();
+        assert!(table.is_empty());
+
+        table
+            .insert(LookupEntry {
+                decimal: dec!(1.91),
+                american: -110,
+                fraction: frac(10, 11),
+            })
+            .unwrap();
+        assert_eq!(table.len(), 1);
+        assert_eq!(table.decimal_to_fraction(dec!(1.910)), Some(frac(10, 11)));
+        assert_eq!(table.american_to_fraction(-110), Some(frac(10, 11)));
+        assert_eq!(table.american_to_decimal(-110), Some(dec!(1.91)));
+        assert_eq!(table.american_to_decimal(-120), None);
+        assert_eq!(table.fraction_to_decimal(frac(10, 11)), Some(dec!(1.91)));
+        assert_eq!(table.fraction_to_american(frac(10, 11)), Some(-110));
+        assert_eq!(table.fraction_to_american(frac(20, 22)), None);
+        assert_eq!(table.decimal_to_american(dec!(1.91)), Some(-110));
+
+        let invalid = LookupEntry {
+            decimal: dec!(1.91),
+            american: -99,
+            fraction: frac(10, 11),
+        };
+        assert_eq!(
+            table.insert(invalid),
+            Err(ConversionError::InvalidAmerican(-99))
+        );
+        assert_eq!(
+            LookupTable::from_entries([invalid]),
+            Err(ConversionError::InvalidAmerican(-99))
+        );
+        assert_eq!(table.len(), 1);
+    }
+
+    #[test]
+    fn test_replace_entry() {
+        let mut table = LookupTable::from_csv(BOARD).unwrap();
+        table
+            .insert(LookupEntry {
+                decimal: dec!(1.910),
+                american: -111,
+                fraction: frac(91, 100),
+            })
+            .unwrap();
+        assert_eq!(table.len(), 4);
+        assert_eq!(
+            table.to_csv(),
+            "decimal,american,fraction\n1.910,-111,91/100\n1.8,-125,4/5\n3.75,275,11/4\n3.125,213,85/40\n"
+        );
+        assert_eq!(table.decimal_to_fraction(dec!(1.91)), Some(frac(91, 100)));
+        assert_eq!(table.decimal_to_american(dec!(1.91)), Some(-111));
+        // Mappings of the replaced entry are gone
+        assert_eq!(table.american_to_decimal(-110), None);
+        assert_eq!(table.american_to_fraction(-110), None);
+        assert_eq!(table.fraction_to_decimal(frac(10, 11)), None);
+        assert_eq!(table.fraction_to_american(frac(10, 11)), None);
+
+        // Entry conflicting with two others replaces both
+        table
+            .insert(LookupEntry {
+                decimal: dec!(1.8),
+                american: 275,
+                fraction: frac(4, 5),
+            })
+            .unwrap();
+        assert_eq!(table.len(), 3);
+        assert_eq!(
+            table.to_csv(),
+            "decimal,american,fraction\n1.910,-111,91/100\n1.8,275,4/5\n3.125,213,85/40\n"
+        );
+        assert_eq!(table.american_to_decimal(-125), None);
+        assert_eq!(table.decimal_to_fraction(dec!(3.75)), None);
+        assert_eq!(table.fraction_to_american(frac(11, 4)), None);
+        assert_eq!(table.american_to_decimal(275), Some(dec!(1.8)));
+    }
+
+    #[test]
+    fn test_csv() {
+        let table = LookupTable::from_csv(BOARD).unwrap();
+        assert_eq!(table.len(), 4);
+        // Traditional form is kept
+        assert_eq!(table.decimal_to_fraction(dec!(3.125)), Some(frac(85, 40)));
+        assert_eq!(table.american_to_fraction(275), Some(frac(11, 4)));
+
+        let csv = table.to_csv();
+        assert!(csv.starts_with("decimal,american,fraction\n1.91,-110,10/11\n"));
+        assert_eq!(csv.parse::<LookupTable>(), Ok(table));
+
+        assert_eq!(
+            LookupTable::from_csv("1.91,-110,10/11\n1.8,-125"),
+            Err(ParseLookupTableError {
+                line: 2,
+                error: ParseOddsError::InvalidFormat
+            })
+        );
+        let error = LookupTable::from_csv("1.91,-110,10/0").unwrap_err();
+        assert_eq!(
+            error.error,
+            ParseOddsError::InvalidOdds(ConversionError::DenominatorZero { num: 10 })
+        );
+        assert_eq!(
+            error.to_string(),
+            "line 1: invalid odds value: denominator of fractional odds 10/0 cannot be zero"
+        );
+    }
+
+    #[test]
+    fn test_custom_lookup() {
+        let table = Arc::new(LookupTable::from_csv(BOARD).unwrap());
+        let config = ConversionConfig::default().custom_lookup(table.clone());
+        assert_eq!(
+            config.lookup_tables_variant,
+            LookupVariant::Custom(table.clone())
+        );
+
+        let outcome = decimal_to_fractional_explained(dec!(3.125), &config).unwrap();
+        assert_eq!(outcome.value, frac(85, 40));
+        assert_eq!(outcome.provenance, Provenance::CustomLookup);
+        assert!(outcome.is_lookup());
+
+        assert_eq!(american_to_fractional_custom(275, &config), Ok(frac(11, 4)));
+        assert_eq!(american_to_decimal_custom(-110, &config), Ok(dec!(1.91)));
+        assert_eq!(
+            fractional_to_decimal_custom(85, 40, &config),
+            Ok(dec!(3.125))
+        );
+        assert_eq!(fractional_to_american_custom(85, 40, &config), Ok(213));
+        assert_eq!(decimal_to_american_custom(dec!(1.8), &config), Ok(-125));
+        // 17/8 is not on the board
+        assert_eq!(
+            fractional_to_decimal_explained(17, 8, &config)
+                .unwrap()
+                .provenance,
+            Provenance::Formula
+        );
+
+        // Built-in tables are not used
+        assert_eq!(
+            american_to_fractional_custom(-150, &ConversionConfig::default()),
+            Ok(frac(4, 6))
+        );
+        assert_eq!(american_to_fractional_custom(-150, &config), Ok(frac(2, 3)));
+
+        // Price rounding is honoured, 1.91 is longer than -110
+        let shorten = config.clone().price_rounding(PriceRounding::Shorten);
+        assert_ne!(american_to_decimal_custom(-110, &shorten), Ok(dec!(1.91)));
+
+        assert_eq!(
+            lookup_decimal_to_fraction_with_config(dec!(1.8), &config),
+            Some((4, 5))
+        );
+        assert_eq!(
+            lookup_decimal_to_fraction_with_config(dec!(1.25), &config),
+            None
+        );
+        assert_eq!(
+            lookup_fraction_at_or_below_with_config(dec!(3.5), &config),
+            Some((85, 40))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let table = LookupTable::from_csv(BOARD).unwrap();
+
+        let json = serde_json::to_string(&table).unwrap();
+        assert!(
+            json.starts_with(
+                r#"{"entries":[{"decimal":"1.91","american":-110,"fraction":"10/11"},"#
+            )
+        );
+        assert_eq!(serde_json::from_str::<LookupTable>(&json).unwrap(), table);
+
+        let toml = r#"
+            [[entries]]
+            decimal = "1.91"
+            american = -110
+            fraction = "10/11"
+
+            [[entries]]
+            decimal = "3.75"
+            american = 275
+            fraction = "11/4"
+        "#;
+        let parsed: LookupTable = toml::from_str(toml).unwrap();
+        assert_eq!(parsed.american_to_fraction(275), Some(frac(11, 4)));
+
+        // Entries are validated
+        assert!(
+            serde_json::from_str::<LookupTable>(
+                r#"{"entries":[{"decimal":"0.5","american":-110,"fraction":"10/11"}]}"#
+            )
+            .is_err()
+        );
+    }
+}
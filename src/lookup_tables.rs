@@ -1,502 +1,1162 @@
-use std::collections::HashMap;
-use std::sync::OnceLock;
+//! Built-in lookup tables as static arrays sorted by key, searched with binary search.
+//!
+//! Decimal keys are stored as integers scaled by `10^DECIMAL_KEY_SCALE`, which are much cheaper
+//! to compare than `Decimal` values.
+//!
+//! Forward tables match the ones generated from `TableRules::basic()` and `TableRules::extended()`,
+//! reverse tables are their inverses.
+
+use std::marker::PhantomData;
 
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 
-// Accessor for DECIMAL_TO_FRACTION Map
-static DECIMAL_TO_FRACTION: OnceLock<HashMap<Decimal, (u32, u32)>> = OnceLock::new();
+/// Decimal places of decimal keys in the built-in tables.
+const DECIMAL_KEY_SCALE: u32 = 4;
+
+/// Scaled key of a decimal in the built-in tables, checked at compile time.
+const fn key(value: Decimal) -> u64 {
+    assert!(value.is_sign_positive() && value.scale() <= DECIMAL_KEY_SCALE);
+    value.mantissa() as u64 * 10u64.pow(DECIMAL_KEY_SCALE - value.scale())
+}
+
+/// Key of a built-in table, stored in a form which is cheap to compare.
+pub trait TableKey: Sized {
+    type Stored: Ord + Copy + 'static;
+
+    /// Stored form of the key, `None` if the key cannot be in the table.
+    fn to_stored(&self) -> Option<Self::Stored>;
+
+    fn from_stored(stored: Self::Stored) -> Self;
+}
+
+impl TableKey for Decimal {
+    type Stored = u64;
+
+    fn to_stored(&self) -> Option<u64> {
+        let (mantissa, scale) = (u128::try_from(self.mantissa()).ok()?, self.scale());
+        let scaled = if scale <= DECIMAL_KEY_SCALE {
+            mantissa * 10u128.pow(DECIMAL_KEY_SCALE - scale)
+        } else {
+            // Only trailing zeros may be dropped, f. ex. 1.910000
+            let divisor = 10u128.pow(scale - DECIMAL_KEY_SCALE);
+            if mantissa % divisor != 0 {
+                return None;
+            }
+            mantissa / divisor
+        };
+        u64::try_from(scaled).ok()
+    }
+
+    fn from_stored(stored: u64) -> Self {
+        Decimal::new(stored as i64, DECIMAL_KEY_SCALE).normalize()
+    }
+}
+
+impl TableKey for i32 {
+    type Stored = i32;
+
+    fn to_stored(&self) -> Option<i32> {
+        Some(*self)
+    }
+
+    fn from_stored(stored: i32) -> Self {
+        stored
+    }
+}
+
+impl TableKey for (u32, u32) {
+    type Stored = (u32, u32);
+
+    fn to_stored(&self) -> Option<(u32, u32)> {
+        Some(*self)
+    }
+
+    fn from_stored(stored: (u32, u32)) -> Self {
+        stored
+    }
+}
+
+/// Built-in lookup table with entries sorted by the stored key.
+pub struct StaticTable<K: TableKey, V: 'static> {
+    entries: &'static [(K::Stored, V)],
+    key: PhantomData<fn() -> K>,
+}
+
+impl<K: TableKey, V> StaticTable<K, V> {
+    const fn new(entries: &'static [(K::Stored, V)]) -> Self {
+        Self {
+            entries,
+            key: PhantomData,
+        }
+    }
+
+    pub fn get(&self, key: &K) -> Option<&'static V> {
+        let stored = key.to_stored()?;
+        let entries = self.entries;
+        entries
+            .binary_search_by(|(key, _)| key.cmp(&stored))
+            .ok()
+            .map(|index| &entries[index].1)
+    }
+
+    /// Entries in the order of stored keys (price order for decimal and american keys).
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (K, &'static V)> + use<K, V> {
+        self.entries
+            .iter()
+            .map(|(key, value)| (K::from_stored(*key), value))
+    }
+
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &'static V> + use<K, V> {
+        self.entries.iter().map(|(_, value)| value)
+    }
+}
+
+static DECIMAL_TO_FRACTION: StaticTable<Decimal, (u32, u32)> = StaticTable::new(&[
+    (key(dec!(1.01)), (1, 100)),
+    (key(dec!(1.2)), (1, 5)),
+    (key(dec!(1.22)), (2, 9)),
+    (key(dec!(1.25)), (1, 4)),
+    (key(dec!(1.29)), (2, 7)),
+    (key(dec!(1.3)), (3, 10)),
+    (key(dec!(1.33)), (1, 3)),
+    (key(dec!(1.36)), (4, 11)),
+    (key(dec!(1.4)), (2, 5)),
+    (key(dec!(1.44)), (4, 9)),
+    (key(dec!(1.5)), (1, 2)),
+    (key(dec!(1.53)), (8, 15)),
+    (key(dec!(1.57)), (4, 7)),
+    (key(dec!(1.62)), (8, 13)),
+    (key(dec!(1.67)), (4, 6)),
+    (key(dec!(1.73)), (8, 11)),
+    (key(dec!(1.8)), (4, 5)),
+    (key(dec!(1.83)), (5, 6)),
+    (key(dec!(1.91)), (10, 11)),
+    (key(dec!(2)), (1, 1)),
+    (key(dec!(2.05)), (21, 20)),
+    (key(dec!(2.1)), (11, 10)),
+    (key(dec!(2.15)), (23, 20)),
+    (key(dec!(2.2)), (6, 5)),
+    (key(dec!(2.25)), (5, 4)),
+    (key(dec!(2.38)), (11, 8)),
+    (key(dec!(2.4)), (7, 5)),
+    (key(dec!(2.5)), (6, 4)),
+    (key(dec!(2.6)), (8, 5)),
+    (key(dec!(2.62)), (13, 8)),
+    (key(dec!(2.75)), (7, 4)),
+    (key(dec!(2.8)), (9, 5)),
+    (key(dec!(2.88)), (15, 8)),
+    (key(dec!(3)), (2, 1)),
+    (key(dec!(3.2)), (11, 5)),
+    (key(dec!(3.25)), (9, 4)),
+    (key(dec!(3.4)), (12, 5)),
+    (key(dec!(3.5)), (5, 2)),
+    (key(dec!(3.6)), (13, 5)),
+    (key(dec!(3.75)), (11, 4)),
+    (key(dec!(4)), (3, 1)),
+    (key(dec!(4.2)), (16, 5)),
+    (key(dec!(4.33)), (10, 3)),
+    (key(dec!(4.5)), (7, 2)),
+    (key(dec!(5)), (4, 1)),
+    (key(dec!(5.5)), (9, 2)),
+    (key(dec!(6)), (5, 1)),
+    (key(dec!(6.5)), (11, 2)),
+    (key(dec!(7)), (6, 1)),
+    (key(dec!(7.5)), (13, 2)),
+    (key(dec!(8)), (7, 1)),
+    (key(dec!(8.5)), (15, 2)),
+    (key(dec!(9)), (8, 1)),
+    (key(dec!(10)), (9, 1)),
+    (key(dec!(11)), (10, 1)),
+    (key(dec!(12)), (11, 1)),
+    (key(dec!(13)), (12, 1)),
+    (key(dec!(14)), (13, 1)),
+    (key(dec!(15)), (14, 1)),
+    (key(dec!(16)), (15, 1)),
+    (key(dec!(17)), (16, 1)),
+    (key(dec!(19)), (18, 1)),
+    (key(dec!(21)), (20, 1)),
+    (key(dec!(26)), (25, 1)),
+    (key(dec!(34)), (33, 1)),
+    (key(dec!(51)), (50, 1)),
+    (key(dec!(67)), (66, 1)),
+    (key(dec!(101)), (100, 1)),
+    (key(dec!(1001)), (1000, 1)),
+]);
 
 /// Lookup table for conversion from decimal to fractional.
-pub fn get_decimal_to_fraction_map() -> &'static HashMap<Decimal, (u32, u32)> {
-    DECIMAL_TO_FRACTION.get_or_init(|| {
-        let mut m = HashMap::new();
-        m.insert(dec!(1.01), (1, 100));
-        m.insert(dec!(1.2), (1, 5));
-        m.insert(dec!(1.22), (2, 9));
-        m.insert(dec!(1.25), (1, 4));
-        m.insert(dec!(1.29), (2, 7));
-        m.insert(dec!(1.3), (3, 10));
-        m.insert(dec!(1.33), (1, 3));
-        m.insert(dec!(1.36), (4, 11));
-        m.insert(dec!(1.4), (2, 5));
-        m.insert(dec!(1.44), (4, 9));
-        m.insert(dec!(1.5), (1, 2));
-        m.insert(dec!(1.53), (8, 15));
-        m.insert(dec!(1.57), (4, 7));
-        m.insert(dec!(1.62), (8, 13));
-        m.insert(dec!(1.67), (4, 6));
-        m.insert(dec!(1.73), (8, 11));
-        m.insert(dec!(1.8), (4, 5));
-        m.insert(dec!(1.83), (5, 6));
-        m.insert(dec!(1.91), (10, 11));
-        m.insert(dec!(2), (1, 1));
-        m.insert(dec!(2.05), (21, 20));
-        m.insert(dec!(2.1), (11, 10));
-        m.insert(dec!(2.15), (23, 20));
-        m.insert(dec!(2.2), (6, 5));
-        m.insert(dec!(2.25), (5, 4));
-        m.insert(dec!(2.38), (11, 8));
-        m.insert(dec!(2.4), (7, 5));
-        m.insert(dec!(2.5), (6, 4));
-        m.insert(dec!(2.6), (8, 5));
-        m.insert(dec!(2.62), (13, 8));
-        m.insert(dec!(2.75), (7, 4));
-        m.insert(dec!(2.8), (9, 5));
-        m.insert(dec!(2.88), (15, 8));
-        m.insert(dec!(3), (2, 1));
-        m.insert(dec!(3.2), (11, 5));
-        m.insert(dec!(3.25), (9, 4));
-        m.insert(dec!(3.4), (12, 5));
-        m.insert(dec!(3.5), (5, 2));
-        m.insert(dec!(3.6), (13, 5));
-        m.insert(dec!(3.75), (11, 4));
-        m.insert(dec!(4), (3, 1));
-        m.insert(dec!(4.2), (16, 5));
-        m.insert(dec!(4.33), (10, 3));
-        m.insert(dec!(4.5), (7, 2));
-        m.insert(dec!(5), (4, 1));
-        m.insert(dec!(5.5), (9, 2));
-        m.insert(dec!(6), (5, 1));
-        m.insert(dec!(6.5), (11, 2));
-        m.insert(dec!(7), (6, 1));
-        m.insert(dec!(7.5), (13, 2));
-        m.insert(dec!(8), (7, 1));
-        m.insert(dec!(8.5), (15, 2));
-        m.insert(dec!(9), (8, 1));
-        m.insert(dec!(10), (9, 1));
-        m.insert(dec!(11), (10, 1));
-        m.insert(dec!(12), (11, 1));
-        m.insert(dec!(13), (12, 1));
-        m.insert(dec!(14), (13, 1));
-        m.insert(dec!(15), (14, 1));
-        m.insert(dec!(16), (15, 1));
-        m.insert(dec!(17), (16, 1));
-        m.insert(dec!(19), (18, 1));
-        m.insert(dec!(21), (20, 1));
-        m.insert(dec!(26), (25, 1));
-        m.insert(dec!(34), (33, 1));
-        m.insert(dec!(51), (50, 1));
-        m.insert(dec!(67), (66, 1));
-        m.insert(dec!(101), (100, 1));
-        m.insert(dec!(1001), (1000, 1));
-
-        m
-    })
+pub fn get_decimal_to_fraction_map() -> &'static StaticTable<Decimal, (u32, u32)> {
+    &DECIMAL_TO_FRACTION
 }
 
-// Accessor for DECIMAL_TO_FRACTION extended Map
-static DECIMAL_TO_FRACTION_EXTENDED: OnceLock<HashMap<Decimal, (u32, u32)>> = OnceLock::new();
+static DECIMAL_TO_FRACTION_EXTENDED: StaticTable<Decimal, (u32, u32)> = StaticTable::new(&[
+    (key(dec!(1.0010)), (1, 1000)),
+    (key(dec!(1.0013)), (1, 750)),
+    (key(dec!(1.0020)), (1, 500)),
+    (key(dec!(1.0025)), (1, 400)),
+    (key(dec!(1.0030)), (1, 300)),
+    (key(dec!(1.0040)), (1, 250)),
+    (key(dec!(1.0050)), (1, 200)),
+    (key(dec!(1.0070)), (1, 150)),
+    (key(dec!(1.0120)), (1, 80)),
+    (key(dec!(1.0150)), (1, 66)),
+    (key(dec!(1.0200)), (1, 50)),
+    (key(dec!(1.0250)), (1, 40)),
+    (key(dec!(1.0300)), (1, 33)),
+    (key(dec!(1.0400)), (1, 25)),
+    (key(dec!(1.0500)), (1, 20)),
+    (key(dec!(1.0550)), (1, 18)),
+    (key(dec!(1.0600)), (1, 16)),
+    (key(dec!(1.0700)), (1, 14)),
+    (key(dec!(1.0800)), (1, 12)),
+    (key(dec!(1.0900)), (1, 11)),
+    (key(dec!(1.1000)), (1, 10)),
+    (key(dec!(1.1100)), (1, 9)),
+    (key(dec!(1.1200)), (1, 8)),
+    (key(dec!(1.1300)), (2, 15)),
+    (key(dec!(1.1400)), (1, 7)),
+    (key(dec!(1.1500)), (2, 13)),
+    (key(dec!(1.1600)), (1, 6)),
+    (key(dec!(1.1800)), (2, 11)),
+    (key(dec!(1.1900)), (19, 100)),
+    (key(dec!(1.2100)), (21, 100)),
+    (key(dec!(1.2300)), (23, 100)),
+    (key(dec!(1.2400)), (6, 25)),
+    (key(dec!(1.2600)), (13, 50)),
+    (key(dec!(1.2700)), (27, 100)),
+    (key(dec!(1.3100)), (31, 100)),
+    (key(dec!(1.3200)), (8, 25)),
+    (key(dec!(1.3400)), (17, 50)),
+    (key(dec!(1.3500)), (7, 20)),
+    (key(dec!(1.3700)), (37, 100)),
+    (key(dec!(1.3800)), (19, 50)),
+    (key(dec!(1.3900)), (39, 100)),
+    (key(dec!(1.4100)), (41, 100)),
+    (key(dec!(1.4200)), (21, 50)),
+    (key(dec!(1.4300)), (43, 100)),
+    (key(dec!(1.4500)), (9, 20)),
+    (key(dec!(1.4600)), (23, 50)),
+    (key(dec!(1.4700)), (40, 85)),
+    (key(dec!(1.4800)), (12, 25)),
+    (key(dec!(1.4900)), (49, 100)),
+    (key(dec!(1.5100)), (51, 100)),
+    (key(dec!(1.5200)), (13, 25)),
+    (key(dec!(1.5400)), (27, 50)),
+    (key(dec!(1.5500)), (11, 20)),
+    (key(dec!(1.5600)), (14, 25)),
+    (key(dec!(1.5800)), (29, 50)),
+    (key(dec!(1.5900)), (59, 100)),
+    (key(dec!(1.6000)), (3, 5)),
+    (key(dec!(1.6100)), (8, 13)),
+    (key(dec!(1.6300)), (63, 100)),
+    (key(dec!(1.6400)), (16, 25)),
+    (key(dec!(1.6500)), (13, 20)),
+    (key(dec!(1.6600)), (4, 6)),
+    (key(dec!(1.6800)), (34, 50)),
+    (key(dec!(1.6900)), (69, 100)),
+    (key(dec!(1.7000)), (7, 10)),
+    (key(dec!(1.7100)), (71, 100)),
+    (key(dec!(1.7200)), (8, 11)),
+    (key(dec!(1.7400)), (37, 50)),
+    (key(dec!(1.7500)), (3, 4)),
+    (key(dec!(1.7600)), (19, 25)),
+    (key(dec!(1.7700)), (77, 100)),
+    (key(dec!(1.7800)), (39, 50)),
+    (key(dec!(1.7900)), (79, 100)),
+    (key(dec!(1.8100)), (81, 100)),
+    (key(dec!(1.8200)), (41, 50)),
+    (key(dec!(1.8400)), (21, 25)),
+    (key(dec!(1.8500)), (17, 20)),
+    (key(dec!(1.8600)), (20, 23)),
+    (key(dec!(1.8700)), (87, 100)),
+    (key(dec!(1.8800)), (22, 25)),
+    (key(dec!(1.8900)), (89, 100)),
+    (key(dec!(1.9000)), (9, 10)),
+    (key(dec!(1.9200)), (23, 25)),
+    (key(dec!(1.9300)), (93, 100)),
+    (key(dec!(1.9400)), (47, 50)),
+    (key(dec!(1.9500)), (20, 21)),
+    (key(dec!(1.9600)), (24, 25)),
+    (key(dec!(1.9700)), (97, 100)),
+    (key(dec!(1.9800)), (49, 50)),
+    (key(dec!(1.9900)), (99, 100)),
+    (key(dec!(2.0100)), (101, 100)),
+    (key(dec!(2.0200)), (51, 50)),
+    (key(dec!(2.0300)), (103, 100)),
+    (key(dec!(2.0400)), (26, 25)),
+    (key(dec!(2.0600)), (53, 50)),
+    (key(dec!(2.0700)), (107, 100)),
+    (key(dec!(2.0800)), (27, 25)),
+    (key(dec!(2.0900)), (109, 100)),
+    (key(dec!(2.1100)), (111, 100)),
+    (key(dec!(2.1200)), (28, 25)),
+    (key(dec!(2.1300)), (113, 100)),
+    (key(dec!(2.1400)), (57, 50)),
+    (key(dec!(2.1600)), (29, 25)),
+    (key(dec!(2.1700)), (117, 100)),
+    (key(dec!(2.1800)), (59, 50)),
+    (key(dec!(2.1900)), (119, 100)),
+    (key(dec!(2.2100)), (121, 100)),
+    (key(dec!(2.2200)), (61, 50)),
+    (key(dec!(2.2300)), (123, 100)),
+    (key(dec!(2.2400)), (31, 25)),
+    (key(dec!(2.2600)), (63, 50)),
+    (key(dec!(2.2700)), (127, 100)),
+    (key(dec!(2.2800)), (32, 25)),
+    (key(dec!(2.3000)), (13, 10)),
+    (key(dec!(2.3200)), (33, 25)),
+    (key(dec!(2.3400)), (67, 50)),
+    (key(dec!(2.3500)), (27, 20)),
+    (key(dec!(2.3600)), (34, 25)),
+    (key(dec!(2.3700)), (11, 8)),
+    (key(dec!(2.4200)), (71, 50)),
+    (key(dec!(2.4400)), (36, 25)),
+    (key(dec!(2.4500)), (29, 20)),
+    (key(dec!(2.4600)), (73, 50)),
+    (key(dec!(2.4800)), (37, 25)),
+    (key(dec!(2.5200)), (38, 25)),
+    (key(dec!(2.5400)), (77, 50)),
+    (key(dec!(2.5600)), (39, 25)),
+    (key(dec!(2.5800)), (79, 50)),
+    (key(dec!(2.6400)), (41, 25)),
+    (key(dec!(2.6600)), (83, 50)),
+    (key(dec!(2.6800)), (42, 25)),
+    (key(dec!(2.7000)), (17, 10)),
+    (key(dec!(2.7200)), (43, 25)),
+    (key(dec!(2.7400)), (87, 50)),
+    (key(dec!(2.7600)), (44, 25)),
+    (key(dec!(2.7800)), (89, 50)),
+    (key(dec!(2.8200)), (91, 50)),
+    (key(dec!(2.8400)), (46, 25)),
+    (key(dec!(2.8600)), (93, 50)),
+    (key(dec!(2.8700)), (15, 8)),
+    (key(dec!(2.9000)), (19, 10)),
+    (key(dec!(2.9200)), (48, 25)),
+    (key(dec!(2.9400)), (97, 50)),
+    (key(dec!(2.9600)), (49, 25)),
+    (key(dec!(2.9800)), (99, 50)),
+    (key(dec!(3.0500)), (41, 20)),
+    (key(dec!(3.1000)), (21, 10)),
+    (key(dec!(3.1250)), (85, 40)),
+    (key(dec!(3.1500)), (43, 20)),
+    (key(dec!(3.3000)), (23, 10)),
+    (key(dec!(3.3500)), (47, 20)),
+    (key(dec!(3.4500)), (49, 20)),
+    (key(dec!(3.5500)), (51, 20)),
+    (key(dec!(3.6500)), (53, 20)),
+    (key(dec!(3.7000)), (27, 10)),
+    (key(dec!(3.8000)), (14, 5)),
+    (key(dec!(3.8500)), (57, 20)),
+    (key(dec!(3.9500)), (59, 20)),
+    (key(dec!(4.0500)), (61, 20)),
+    (key(dec!(4.1000)), (31, 10)),
+    (key(dec!(4.1500)), (63, 20)),
+    (key(dec!(4.2500)), (13, 4)),
+    (key(dec!(4.3000)), (33, 10)),
+    (key(dec!(4.3500)), (67, 20)),
+    (key(dec!(4.4000)), (17, 5)),
+    (key(dec!(4.4500)), (69, 20)),
+    (key(dec!(4.5500)), (71, 20)),
+    (key(dec!(4.6000)), (18, 5)),
+    (key(dec!(4.6500)), (73, 20)),
+    (key(dec!(4.7000)), (37, 10)),
+    (key(dec!(4.7500)), (15, 4)),
+    (key(dec!(4.8000)), (19, 5)),
+    (key(dec!(4.8500)), (77, 20)),
+    (key(dec!(4.9000)), (39, 10)),
+    (key(dec!(4.9500)), (79, 20)),
+    (key(dec!(5.1000)), (41, 10)),
+    (key(dec!(5.2000)), (21, 5)),
+    (key(dec!(5.3000)), (43, 10)),
+    (key(dec!(5.4000)), (22, 5)),
+    (key(dec!(5.6000)), (23, 5)),
+    (key(dec!(5.7000)), (47, 10)),
+    (key(dec!(5.8000)), (24, 5)),
+    (key(dec!(5.9000)), (49, 10)),
+    (key(dec!(6.2000)), (26, 5)),
+    (key(dec!(6.4000)), (27, 5)),
+    (key(dec!(6.6000)), (28, 5)),
+    (key(dec!(6.8000)), (29, 5)),
+    (key(dec!(7.2000)), (31, 5)),
+    (key(dec!(7.4000)), (32, 5)),
+    (key(dec!(7.6000)), (33, 5)),
+    (key(dec!(7.8000)), (34, 5)),
+    (key(dec!(8.2000)), (36, 5)),
+    (key(dec!(8.4000)), (37, 5)),
+    (key(dec!(8.6000)), (38, 5)),
+    (key(dec!(8.8000)), (39, 5)),
+    (key(dec!(9.2000)), (41, 5)),
+    (key(dec!(9.4000)), (42, 5)),
+    (key(dec!(9.5000)), (17, 2)),
+    (key(dec!(9.6000)), (43, 5)),
+    (key(dec!(9.8000)), (44, 5)),
+    (key(dec!(23.0000)), (22, 1)),
+    (key(dec!(29.0000)), (28, 1)),
+    (key(dec!(31.0000)), (30, 1)),
+    (key(dec!(36.0000)), (35, 1)),
+    (key(dec!(41.0000)), (40, 1)),
+    (key(dec!(46.0000)), (45, 1)),
+    (key(dec!(56.0000)), (55, 1)),
+    (key(dec!(61.0000)), (60, 1)),
+    (key(dec!(71.0000)), (70, 1)),
+    (key(dec!(76.0000)), (75, 1)),
+    (key(dec!(81.0000)), (80, 1)),
+    (key(dec!(86.0000)), (85, 1)),
+    (key(dec!(91.0000)), (90, 1)),
+    (key(dec!(96.0000)), (95, 1)),
+    (key(dec!(111.0000)), (110, 1)),
+    (key(dec!(121.0000)), (120, 1)),
+    (key(dec!(126.0000)), (125, 1)),
+    (key(dec!(131.0000)), (130, 1)),
+    (key(dec!(141.0000)), (140, 1)),
+    (key(dec!(151.0000)), (150, 1)),
+    (key(dec!(176.0000)), (175, 1)),
+    (key(dec!(201.0000)), (200, 1)),
+    (key(dec!(226.0000)), (225, 1)),
+    (key(dec!(251.0000)), (250, 1)),
+    (key(dec!(276.0000)), (275, 1)),
+    (key(dec!(301.0000)), (300, 1)),
+    (key(dec!(401.0000)), (400, 1)),
+    (key(dec!(501.0000)), (500, 1)),
+]);
 
 /// Extended lookup table for conversion from decimal to fractional.
-pub fn get_decimal_to_fraction_extended_map() -> &'static HashMap<Decimal, (u32, u32)> {
-    DECIMAL_TO_FRACTION_EXTENDED.get_or_init(|| {
-        let mut m = HashMap::new();
-        m.insert(dec!(1.0010), (1, 1000));
-        m.insert(dec!(1.0013), (1, 750));
-        m.insert(dec!(1.0020), (1, 500));
-        m.insert(dec!(1.0025), (1, 400));
-        m.insert(dec!(1.0030), (1, 300));
-        m.insert(dec!(1.0040), (1, 250));
-        m.insert(dec!(1.0050), (1, 200));
-        m.insert(dec!(1.0070), (1, 150));
-        m.insert(dec!(1.0120), (1, 80));
-        m.insert(dec!(1.0150), (1, 66));
-        m.insert(dec!(1.0200), (1, 50));
-        m.insert(dec!(1.0250), (1, 40));
-        m.insert(dec!(1.0300), (1, 33));
-        m.insert(dec!(1.0400), (1, 25));
-        m.insert(dec!(1.0500), (1, 20));
-        m.insert(dec!(1.0550), (1, 18));
-        m.insert(dec!(1.0600), (1, 16));
-        m.insert(dec!(1.0700), (1, 14));
-        m.insert(dec!(1.0800), (1, 12));
-        m.insert(dec!(1.0900), (1, 11));
-        m.insert(dec!(1.1000), (1, 10));
-        m.insert(dec!(1.1100), (1, 9));
-        m.insert(dec!(1.1200), (1, 8));
-        m.insert(dec!(1.1300), (2, 15));
-        m.insert(dec!(1.1400), (1, 7));
-        m.insert(dec!(1.1500), (2, 13));
-        m.insert(dec!(1.1600), (1, 6));
-        m.insert(dec!(1.1800), (2, 11)); // D
-        m.insert(dec!(1.1900), (19, 100)); // D
-        m.insert(dec!(1.2100), (21, 100)); // D
-        m.insert(dec!(1.2300), (23, 100)); // D
-        m.insert(dec!(1.2400), (6, 25)); // D
-        m.insert(dec!(1.2600), (13, 50)); // D
-        m.insert(dec!(1.2700), (27, 100)); // D
-        m.insert(dec!(1.3100), (31, 100)); // D
-        m.insert(dec!(1.3200), (8, 25)); // D
-        m.insert(dec!(1.3400), (17, 50)); // D
-        m.insert(dec!(1.3500), (7, 20)); // D
-        m.insert(dec!(1.3700), (37, 100)); // D
-        m.insert(dec!(1.3800), (19, 50)); // D
-        m.insert(dec!(1.3900), (39, 100)); // D
-        m.insert(dec!(1.4100), (41, 100)); // D
-        m.insert(dec!(1.4200), (21, 50)); // D
-        m.insert(dec!(1.4300), (43, 100)); // D
-        m.insert(dec!(1.4500), (9, 20)); // D
-        m.insert(dec!(1.4600), (23, 50)); // D
-        m.insert(dec!(1.4700), (40, 85));
-        m.insert(dec!(1.4800), (12, 25)); // D
-        m.insert(dec!(1.4900), (49, 100)); // D
-        m.insert(dec!(1.5100), (51, 100)); // D
-        m.insert(dec!(1.5200), (13, 25)); // D
-        m.insert(dec!(1.5400), (27, 50)); // D
-        m.insert(dec!(1.5500), (11, 20)); // D
-        m.insert(dec!(1.5600), (14, 25)); // D
-        m.insert(dec!(1.5800), (29, 50)); // D
-        m.insert(dec!(1.5900), (59, 100)); // D
-        m.insert(dec!(1.6000), (3, 5)); // D
-        m.insert(dec!(1.6100), (8, 13));
-        m.insert(dec!(1.6300), (63, 100)); // D
-        m.insert(dec!(1.6400), (16, 25)); // D
-        m.insert(dec!(1.6500), (13, 20)); // D
-        m.insert(dec!(1.6600), (4, 6));
-        m.insert(dec!(1.6800), (34, 50)); // D
-        m.insert(dec!(1.6900), (69, 100));
-        m.insert(dec!(1.7000), (7, 10)); // D
-        m.insert(dec!(1.7100), (71, 100)); // D
-        m.insert(dec!(1.7200), (8, 11));
-        m.insert(dec!(1.7400), (37, 50)); // D
-        m.insert(dec!(1.7500), (3, 4));
-        m.insert(dec!(1.7600), (19, 25)); // D
-        m.insert(dec!(1.7700), (77, 100)); // D
-        m.insert(dec!(1.7800), (39, 50)); // D
-        m.insert(dec!(1.7900), (79, 100)); // D
-        m.insert(dec!(1.8100), (81, 100)); // D
-        m.insert(dec!(1.8200), (41, 50)); // D
-        m.insert(dec!(1.8400), (21, 25)); // D
-        m.insert(dec!(1.8500), (17, 20)); // D
-        m.insert(dec!(1.8600), (20, 23)); // D
-        m.insert(dec!(1.8700), (87, 100)); // D
-        m.insert(dec!(1.8800), (22, 25)); // D
-        m.insert(dec!(1.8900), (89, 100)); // D
-        m.insert(dec!(1.9000), (9, 10));
-        m.insert(dec!(1.9200), (23, 25)); // D
-        m.insert(dec!(1.9300), (93, 100)); // D
-        m.insert(dec!(1.9400), (47, 50)); // D
-        m.insert(dec!(1.9500), (20, 21));
-        m.insert(dec!(1.9600), (24, 25)); // D
-        m.insert(dec!(1.9700), (97, 100)); // D
-        m.insert(dec!(1.9800), (49, 50)); // D
-        m.insert(dec!(1.9900), (99, 100)); // D
-        m.insert(dec!(2.0100), (101, 100)); // D
-        m.insert(dec!(2.0200), (51, 50)); // D
-        m.insert(dec!(2.0300), (103, 100)); // D
-        m.insert(dec!(2.0400), (26, 25)); // D
-        m.insert(dec!(2.0600), (53, 50)); // D
-        m.insert(dec!(2.0700), (107, 100)); // D
-        m.insert(dec!(2.0800), (27, 25)); // D
-        m.insert(dec!(2.0900), (109, 100)); // D
-        m.insert(dec!(2.1100), (111, 100)); // D
-        m.insert(dec!(2.1200), (28, 25)); // D
-        m.insert(dec!(2.1300), (113, 100)); // D
-        m.insert(dec!(2.1400), (57, 50)); // D
-        m.insert(dec!(2.1600), (29, 25)); // D
-        m.insert(dec!(2.1700), (117, 100)); // D
-        m.insert(dec!(2.1800), (59, 50)); // D
-        m.insert(dec!(2.1900), (119, 100)); // D
-        m.insert(dec!(2.2100), (121, 100)); // D
-        m.insert(dec!(2.2200), (61, 50)); // D
-        m.insert(dec!(2.2300), (123, 100)); // D
-        m.insert(dec!(2.2400), (31, 25)); // D
-        m.insert(dec!(2.2600), (63, 50)); // D
-        m.insert(dec!(2.2700), (127, 100)); // D
-        m.insert(dec!(2.2800), (32, 25)); // D
-        m.insert(dec!(2.3000), (13, 10));
-        m.insert(dec!(2.3200), (33, 25)); // D
-        m.insert(dec!(2.3400), (67, 50)); // D
-        m.insert(dec!(2.3500), (27, 20)); // D
-        m.insert(dec!(2.3600), (34, 25)); // D
-        m.insert(dec!(2.3700), (11, 8));
-        m.insert(dec!(2.4200), (71, 50)); // D
-        m.insert(dec!(2.4400), (36, 25)); // D
-        m.insert(dec!(2.4500), (29, 20));
-        m.insert(dec!(2.4600), (73, 50)); // D
-        m.insert(dec!(2.4800), (37, 25)); // D
-        m.insert(dec!(2.5200), (38, 25)); // D
-        m.insert(dec!(2.5400), (77, 50)); // D
-        m.insert(dec!(2.5600), (39, 25)); // D
-        m.insert(dec!(2.5800), (79, 50)); // D
-        m.insert(dec!(2.6400), (41, 25)); // D
-        m.insert(dec!(2.6600), (83, 50)); // D
-        m.insert(dec!(2.6800), (42, 25)); // D
-        m.insert(dec!(2.7000), (17, 10));
-        m.insert(dec!(2.7200), (43, 25)); // D
-        m.insert(dec!(2.7400), (87, 50)); // D
-        m.insert(dec!(2.7600), (44, 25)); // D
-        m.insert(dec!(2.7800), (89, 50)); // D
-        m.insert(dec!(2.8200), (91, 50)); // D
-        m.insert(dec!(2.8400), (46, 25)); // D
-        m.insert(dec!(2.8600), (93, 50)); // D
-        m.insert(dec!(2.8700), (15, 8)); // D
-        m.insert(dec!(2.9000), (19, 10));
-        m.insert(dec!(2.9200), (48, 25)); // D
-        m.insert(dec!(2.9400), (97, 50));
-        m.insert(dec!(2.9600), (49, 25)); // D
-        m.insert(dec!(2.9800), (99, 50)); // D
-        m.insert(dec!(3.0500), (41, 20)); // D
-        m.insert(dec!(3.1000), (21, 10));
-        m.insert(dec!(3.1250), (85, 40));
-        m.insert(dec!(3.1500), (43, 20)); // D
-        m.insert(dec!(3.3000), (23, 10));
-        m.insert(dec!(3.3500), (47, 20)); // D
-        m.insert(dec!(3.4500), (49, 20)); // D
-        m.insert(dec!(3.5500), (51, 20)); // D
-        m.insert(dec!(3.6500), (53, 20)); // D
-        m.insert(dec!(3.7000), (27, 10)); // D
-        m.insert(dec!(3.8000), (14, 5));
-        m.insert(dec!(3.8500), (57, 20)); // D
-        m.insert(dec!(3.9500), (59, 20)); // D
-        m.insert(dec!(4.0500), (61, 20)); // D
-        m.insert(dec!(4.1000), (31, 10));
-        m.insert(dec!(4.1500), (63, 20)); // D
-        m.insert(dec!(4.2500), (13, 4)); // D
-        m.insert(dec!(4.3000), (33, 10)); // D
-        m.insert(dec!(4.3500), (67, 20)); // D
-        m.insert(dec!(4.4000), (17, 5));
-        m.insert(dec!(4.4500), (69, 20)); // D
-        m.insert(dec!(4.5500), (71, 20)); // D
-        m.insert(dec!(4.6000), (18, 5));
-        m.insert(dec!(4.6500), (73, 20)); // D
-        m.insert(dec!(4.7000), (37, 10)); // D
-        m.insert(dec!(4.7500), (15, 4));
-        m.insert(dec!(4.8000), (19, 5));
-        m.insert(dec!(4.8500), (77, 20)); // D
-        m.insert(dec!(4.9000), (39, 10)); // D
-        m.insert(dec!(4.9500), (79, 20)); // D
-        m.insert(dec!(5.1000), (41, 10)); // D
-        m.insert(dec!(5.2000), (21, 5)); // D
-        m.insert(dec!(5.3000), (43, 10)); // D
-        m.insert(dec!(5.4000), (22, 5)); // D
-        m.insert(dec!(5.6000), (23, 5)); // D
-        m.insert(dec!(5.7000), (47, 10)); // D
-        m.insert(dec!(5.8000), (24, 5)); // D
-        m.insert(dec!(5.9000), (49, 10)); // D
-        m.insert(dec!(6.2000), (26, 5)); // D
-        m.insert(dec!(6.4000), (27, 5)); // D
-        m.insert(dec!(6.6000), (28, 5)); // D
-        m.insert(dec!(6.8000), (29, 5)); // D
-        m.insert(dec!(7.2000), (31, 5)); // D
-        m.insert(dec!(7.4000), (32, 5)); // D
-        m.insert(dec!(7.6000), (33, 5)); // D
-        m.insert(dec!(7.8000), (34, 5)); // D
-        m.insert(dec!(8.2000), (36, 5)); // D
-        m.insert(dec!(8.4000), (37, 5)); // D
-        m.insert(dec!(8.6000), (38, 5)); // D
-        m.insert(dec!(8.8000), (39, 5)); // D
-        m.insert(dec!(9.2000), (41, 5)); // D
-        m.insert(dec!(9.4000), (42, 5)); // D
-        m.insert(dec!(9.5000), (17, 2));
-        m.insert(dec!(9.6000), (43, 5)); // D
-        m.insert(dec!(9.8000), (44, 5)); // D
-        m.insert(dec!(23.0000), (22, 1));
-        m.insert(dec!(29.0000), (28, 1));
-        m.insert(dec!(31.0000), (30, 1));
-        m.insert(dec!(36.0000), (35, 1));
-        m.insert(dec!(41.0000), (40, 1));
-        m.insert(dec!(46.0000), (45, 1));
-        m.insert(dec!(56.0000), (55, 1));
-        m.insert(dec!(61.0000), (60, 1));
-        m.insert(dec!(71.0000), (70, 1));
-        m.insert(dec!(76.0000), (75, 1)); // D
-        m.insert(dec!(81.0000), (80, 1));
-        m.insert(dec!(86.0000), (85, 1)); // D
-        m.insert(dec!(91.0000), (90, 1));
-        m.insert(dec!(96.0000), (95, 1)); // D
-        m.insert(dec!(111.0000), (110, 1));
-        m.insert(dec!(121.0000), (120, 1));
-        m.insert(dec!(126.0000), (125, 1));
-        m.insert(dec!(131.0000), (130, 1));
-        m.insert(dec!(141.0000), (140, 1));
-        m.insert(dec!(151.0000), (150, 1));
-        m.insert(dec!(176.0000), (175, 1));
-        m.insert(dec!(201.0000), (200, 1));
-        m.insert(dec!(226.0000), (225, 1));
-        m.insert(dec!(251.0000), (250, 1));
-        m.insert(dec!(276.0000), (275, 1));
-        m.insert(dec!(301.0000), (300, 1));
-        m.insert(dec!(401.0000), (400, 1));
-        m.insert(dec!(501.0000), (500, 1));
-        m
-    })
+pub fn get_decimal_to_fraction_extended_map() -> &'static StaticTable<Decimal, (u32, u32)> {
+    &DECIMAL_TO_FRACTION_EXTENDED
 }
 
-// Accessor for AMERICAN_TO_FRACTION Map
-static AMERICAN_TO_FRACTION: OnceLock<HashMap<i32, (u32, u32)>> = OnceLock::new();
+static AMERICAN_TO_FRACTION: StaticTable<i32, (u32, u32)> = StaticTable::new(&[
+    (-10000, (1, 100)),
+    (-500, (1, 5)),
+    (-450, (2, 9)),
+    (-400, (1, 4)),
+    (-350, (2, 7)),
+    (-333, (3, 10)),
+    (-300, (1, 3)),
+    (-275, (4, 11)),
+    (-250, (2, 5)),
+    (-225, (4, 9)),
+    (-200, (1, 2)),
+    (-188, (8, 15)),
+    (-175, (4, 7)),
+    (-163, (8, 13)),
+    (-150, (4, 6)),
+    (-138, (8, 11)),
+    (-125, (4, 5)),
+    (-120, (5, 6)),
+    (-110, (10, 11)),
+    (100, (1, 1)),
+    (105, (21, 20)),
+    (110, (11, 10)),
+    (115, (23, 20)),
+    (120, (6, 5)),
+    (125, (5, 4)),
+    (138, (11, 8)),
+    (140, (7, 5)),
+    (150, (6, 4)),
+    (160, (8, 5)),
+    (163, (13, 8)),
+    (175, (7, 4)),
+    (180, (9, 5)),
+    (188, (15, 8)),
+    (200, (2, 1)),
+    (220, (11, 5)),
+    (225, (9, 4)),
+    (240, (12, 5)),
+    (250, (5, 2)),
+    (260, (13, 5)),
+    (275, (11, 4)),
+    (300, (3, 1)),
+    (320, (16, 5)),
+    (333, (10, 3)),
+    (350, (7, 2)),
+    (400, (4, 1)),
+    (450, (9, 2)),
+    (500, (5, 1)),
+    (550, (11, 2)),
+    (600, (6, 1)),
+    (650, (13, 2)),
+    (700, (7, 1)),
+    (750, (15, 2)),
+    (800, (8, 1)),
+    (900, (9, 1)),
+    (1000, (10, 1)),
+    (1100, (11, 1)),
+    (1200, (12, 1)),
+    (1300, (13, 1)),
+    (1400, (14, 1)),
+    (1500, (15, 1)),
+    (1600, (16, 1)),
+    (1800, (18, 1)),
+    (2000, (20, 1)),
+    (2500, (25, 1)),
+    (3300, (33, 1)),
+    (5000, (50, 1)),
+    (6600, (66, 1)),
+    (10000, (100, 1)),
+    (100000, (1000, 1)),
+]);
 
 /// Lookup table for conversion from american to fractional.
-pub fn get_american_to_fraction_map() -> &'static HashMap<i32, (u32, u32)> {
-    AMERICAN_TO_FRACTION.get_or_init(|| {
-        let mut m = HashMap::new();
-        m.insert(-10000, (1, 100));
-        m.insert(-500, (1, 5));
-        m.insert(-450, (2, 9));
-        m.insert(-400, (1, 4));
-        m.insert(-350, (2, 7));
-        m.insert(-333, (3, 10));
-        m.insert(-300, (1, 3));
-        m.insert(-275, (4, 11));
-        m.insert(-250, (2, 5));
-        m.insert(-225, (4, 9));
-        m.insert(-200, (1, 2));
-        m.insert(-188, (8, 15));
-        m.insert(-175, (4, 7));
-        m.insert(-163, (8, 13));
-        m.insert(-150, (4, 6));
-        m.insert(-138, (8, 11));
-        m.insert(-125, (4, 5));
-        m.insert(-120, (5, 6));
-        m.insert(-110, (10, 11));
-        m.insert(100, (1, 1));
-        m.insert(105, (21, 20));
-        m.insert(110, (11, 10));
-        m.insert(115, (23, 20));
-        m.insert(120, (6, 5));
-        m.insert(125, (5, 4));
-        m.insert(138, (11, 8));
-        m.insert(140, (7, 5));
-        m.insert(150, (6, 4));
-        m.insert(160, (8, 5));
-        m.insert(163, (13, 8));
-        m.insert(175, (7, 4));
-        m.insert(180, (9, 5));
-        m.insert(188, (15, 8));
-        m.insert(200, (2, 1));
-        m.insert(220, (11, 5));
-        m.insert(225, (9, 4));
-        m.insert(240, (12, 5));
-        m.insert(250, (5, 2));
-        m.insert(260, (13, 5));
-        m.insert(275, (11, 4));
-        m.insert(300, (3, 1));
-        m.insert(320, (16, 5));
-        m.insert(333, (10, 3));
-        m.insert(350, (7, 2));
-        m.insert(400, (4, 1));
-        m.insert(450, (9, 2));
-        m.insert(500, (5, 1));
-        m.insert(550, (11, 2));
-        m.insert(600, (6, 1));
-        m.insert(650, (13, 2));
-        m.insert(700, (7, 1));
-        m.insert(750, (15, 2));
-        m.insert(800, (8, 1));
-        m.insert(900, (9, 1));
-        m.insert(1000, (10, 1));
-        m.insert(1100, (11, 1));
-        m.insert(1200, (12, 1));
-        m.insert(1300, (13, 1));
-        m.insert(1400, (14, 1));
-        m.insert(1500, (15, 1));
-        m.insert(1600, (16, 1));
-        m.insert(1800, (18, 1));
-        m.insert(2000, (20, 1));
-        m.insert(2500, (25, 1));
-        m.insert(3300, (33, 1));
-        m.insert(5000, (50, 1));
-        m.insert(6600, (66, 1));
-        m.insert(10000, (100, 1));
-        m.insert(100000, (1000, 1));
-        m
-    })
+pub fn get_american_to_fraction_map() -> &'static StaticTable<i32, (u32, u32)> {
+    &AMERICAN_TO_FRACTION
 }
 
-// Accessor for AMERICAN_TO_FRACTION extended Map
-static AMERICAN_TO_FRACTION_EXTENDED: OnceLock<HashMap<i32, (u32, u32)>> = OnceLock::new();
+static AMERICAN_TO_FRACTION_EXTENDED: StaticTable<i32, (u32, u32)> = StaticTable::new(&[]);
 
 /// Extended lookup table for conversion from american to fractional.
-pub fn get_american_to_fraction_extended_map() -> &'static HashMap<i32, (u32, u32)> {
-    AMERICAN_TO_FRACTION_EXTENDED.get_or_init(HashMap::new)
+pub fn get_american_to_fraction_extended_map() -> &'static StaticTable<i32, (u32, u32)> {
+    &AMERICAN_TO_FRACTION_EXTENDED
 }
 
-// Accessor for AMERICAN_TO_DECIMAL Map
-static AMERICAN_TO_DECIMAL: OnceLock<HashMap<i32, Decimal>> = OnceLock::new();
+static AMERICAN_TO_DECIMAL: StaticTable<i32, Decimal> = StaticTable::new(&[
+    (-10000, dec!(1.01)),
+    (-500, dec!(1.2)),
+    (-450, dec!(1.22)),
+    (-400, dec!(1.25)),
+    (-350, dec!(1.29)),
+    (-333, dec!(1.3)),
+    (-300, dec!(1.33)),
+    (-275, dec!(1.36)),
+    (-250, dec!(1.4)),
+    (-225, dec!(1.44)),
+    (-200, dec!(1.5)),
+    (-188, dec!(1.53)),
+    (-175, dec!(1.57)),
+    (-163, dec!(1.62)),
+    (-150, dec!(1.67)),
+    (-138, dec!(1.73)),
+    (-125, dec!(1.8)),
+    (-120, dec!(1.83)),
+    (-110, dec!(1.91)),
+    (100, dec!(2)),
+    (105, dec!(2.05)),
+    (110, dec!(2.1)),
+    (115, dec!(2.15)),
+    (120, dec!(2.2)),
+    (125, dec!(2.25)),
+    (138, dec!(2.38)),
+    (140, dec!(2.4)),
+    (150, dec!(2.5)),
+    (160, dec!(2.6)),
+    (163, dec!(2.62)),
+    (175, dec!(2.75)),
+    (180, dec!(2.8)),
+    (188, dec!(2.88)),
+    (200, dec!(3)),
+    (220, dec!(3.2)),
+    (225, dec!(3.25)),
+    (240, dec!(3.4)),
+    (250, dec!(3.5)),
+    (260, dec!(3.6)),
+    (275, dec!(3.75)),
+    (300, dec!(4)),
+    (320, dec!(4.2)),
+    (333, dec!(4.33)),
+    (350, dec!(4.5)),
+    (400, dec!(5)),
+    (450, dec!(5.5)),
+    (500, dec!(6)),
+    (550, dec!(6.5)),
+    (600, dec!(7)),
+    (650, dec!(7.5)),
+    (700, dec!(8)),
+    (750, dec!(8.5)),
+    (800, dec!(9)),
+    (900, dec!(10)),
+    (1000, dec!(11)),
+    (1100, dec!(12)),
+    (1200, dec!(13)),
+    (1300, dec!(14)),
+    (1400, dec!(15)),
+    (1500, dec!(16)),
+    (1600, dec!(17)),
+    (1800, dec!(19)),
+    (2000, dec!(21)),
+    (2500, dec!(26)),
+    (3300, dec!(34)),
+    (5000, dec!(51)),
+    (6600, dec!(67)),
+    (10000, dec!(101)),
+    (100000, dec!(1001)),
+]);
 
 /// Lookup table for conversion from american to decimal.
-pub fn get_american_to_decimal_map() -> &'static HashMap<i32, Decimal> {
-    AMERICAN_TO_DECIMAL.get_or_init(|| {
-        let mut m = HashMap::new();
-        m.insert(-10000, dec!(1.01));
-        m.insert(-500, dec!(1.2));
-        m.insert(-450, dec!(1.22));
-        m.insert(-400, dec!(1.25));
-        m.insert(-350, dec!(1.29));
-        m.insert(-333, dec!(1.3));
-        m.insert(-300, dec!(1.33));
-        m.insert(-275, dec!(1.36));
-        m.insert(-250, dec!(1.4));
-        m.insert(-225, dec!(1.44));
-        m.insert(-200, dec!(1.5));
-        m.insert(-188, dec!(1.53));
-        m.insert(-175, dec!(1.57));
-        m.insert(-163, dec!(1.62));
-        m.insert(-150, dec!(1.67));
-        m.insert(-138, dec!(1.73));
-        m.insert(-125, dec!(1.8));
-        m.insert(-120, dec!(1.83));
-        m.insert(-110, dec!(1.91));
-        m.insert(100, dec!(2));
-        m.insert(105, dec!(2.05));
-        m.insert(110, dec!(2.1));
-        m.insert(115, dec!(2.15));
-        m.insert(120, dec!(2.2));
-        m.insert(125, dec!(2.25));
-        m.insert(138, dec!(2.38));
-        m.insert(140, dec!(2.4));
-        m.insert(150, dec!(2.5));
-        m.insert(160, dec!(2.6));
-        m.insert(163, dec!(2.62));
-        m.insert(175, dec!(2.75));
-        m.insert(180, dec!(2.8));
-        m.insert(188, dec!(2.88));
-        m.insert(200, dec!(3));
-        m.insert(220, dec!(3.2));
-        m.insert(225, dec!(3.25));
-        m.insert(240, dec!(3.4));
-        m.insert(250, dec!(3.5));
-        m.insert(260, dec!(3.6));
-        m.insert(275, dec!(3.75));
-        m.insert(300, dec!(4));
-        m.insert(320, dec!(4.2));
-        m.insert(333, dec!(4.33));
-        m.insert(350, dec!(4.5));
-        m.insert(400, dec!(5));
-        m.insert(450, dec!(5.5));
-        m.insert(500, dec!(6));
-        m.insert(550, dec!(6.5));
-        m.insert(600, dec!(7));
-        m.insert(650, dec!(7.5));
-        m.insert(700, dec!(8));
-        m.insert(750, dec!(8.5));
-        m.insert(800, dec!(9));
-        m.insert(900, dec!(10));
-        m.insert(1000, dec!(11));
-        m.insert(1100, dec!(12));
-        m.insert(1200, dec!(13));
-        m.insert(1300, dec!(14));
-        m.insert(1400, dec!(15));
-        m.insert(1500, dec!(16));
-        m.insert(1600, dec!(17));
-        m.insert(1800, dec!(19));
-        m.insert(2000, dec!(21));
-        m.insert(2500, dec!(26));
-        m.insert(3300, dec!(34));
-        m.insert(5000, dec!(51));
-        m.insert(6600, dec!(67));
-        m.insert(10000, dec!(101));
-        m.insert(100000, dec!(1001));
-        m
-    })
+pub fn get_american_to_decimal_map() -> &'static StaticTable<i32, Decimal> {
+    &AMERICAN_TO_DECIMAL
 }
 
-// Accessor for AMERICAN_TO_DECIMAL extended Map
-static AMERICAN_TO_DECIMAL_EXTENDED: OnceLock<HashMap<i32, Decimal>> = OnceLock::new();
+static AMERICAN_TO_DECIMAL_EXTENDED: StaticTable<i32, Decimal> = StaticTable::new(&[]);
 
 /// Extended lookup table for conversion from american to decimal.
-pub fn get_american_to_decimal_extended_map() -> &'static HashMap<i32, Decimal> {
+pub fn get_american_to_decimal_extended_map() -> &'static StaticTable<i32, Decimal> {
     // TODO
-    AMERICAN_TO_DECIMAL_EXTENDED.get_or_init(HashMap::new)
+    &AMERICAN_TO_DECIMAL_EXTENDED
+}
+
+static FRACTION_TO_DECIMAL: StaticTable<(u32, u32), Decimal> = StaticTable::new(&[
+    ((1, 1), dec!(2)),
+    ((1, 2), dec!(1.5)),
+    ((1, 3), dec!(1.33)),
+    ((1, 4), dec!(1.25)),
+    ((1, 5), dec!(1.2)),
+    ((1, 100), dec!(1.01)),
+    ((2, 1), dec!(3)),
+    ((2, 5), dec!(1.4)),
+    ((2, 7), dec!(1.29)),
+    ((2, 9), dec!(1.22)),
+    ((3, 1), dec!(4)),
+    ((3, 10), dec!(1.3)),
+    ((4, 1), dec!(5)),
+    ((4, 5), dec!(1.8)),
+    ((4, 6), dec!(1.67)),
+    ((4, 7), dec!(1.57)),
+    ((4, 9), dec!(1.44)),
+    ((4, 11), dec!(1.36)),
+    ((5, 1), dec!(6)),
+    ((5, 2), dec!(3.5)),
+    ((5, 4), dec!(2.25)),
+    ((5, 6), dec!(1.83)),
+    ((6, 1), dec!(7)),
+    ((6, 4), dec!(2.5)),
+    ((6, 5), dec!(2.2)),
+    ((7, 1), dec!(8)),
+    ((7, 2), dec!(4.5)),
+    ((7, 4), dec!(2.75)),
+    ((7, 5), dec!(2.4)),
+    ((8, 1), dec!(9)),
+    ((8, 5), dec!(2.6)),
+    ((8, 11), dec!(1.73)),
+    ((8, 13), dec!(1.62)),
+    ((8, 15), dec!(1.53)),
+    ((9, 1), dec!(10)),
+    ((9, 2), dec!(5.5)),
+    ((9, 4), dec!(3.25)),
+    ((9, 5), dec!(2.8)),
+    ((10, 1), dec!(11)),
+    ((10, 3), dec!(4.33)),
+    ((10, 11), dec!(1.91)),
+    ((11, 1), dec!(12)),
+    ((11, 2), dec!(6.5)),
+    ((11, 4), dec!(3.75)),
+    ((11, 5), dec!(3.2)),
+    ((11, 8), dec!(2.38)),
+    ((11, 10), dec!(2.1)),
+    ((12, 1), dec!(13)),
+    ((12, 5), dec!(3.4)),
+    ((13, 1), dec!(14)),
+    ((13, 2), dec!(7.5)),
+    ((13, 5), dec!(3.6)),
+    ((13, 8), dec!(2.62)),
+    ((14, 1), dec!(15)),
+    ((15, 1), dec!(16)),
+    ((15, 2), dec!(8.5)),
+    ((15, 8), dec!(2.88)),
+    ((16, 1), dec!(17)),
+    ((16, 5), dec!(4.2)),
+    ((18, 1), dec!(19)),
+    ((20, 1), dec!(21)),
+    ((21, 20), dec!(2.05)),
+    ((23, 20), dec!(2.15)),
+    ((25, 1), dec!(26)),
+    ((33, 1), dec!(34)),
+    ((50, 1), dec!(51)),
+    ((66, 1), dec!(67)),
+    ((100, 1), dec!(101)),
+    ((1000, 1), dec!(1001)),
+]);
+
+/// Lookup table for conversion from fractional to decimal, reversed decimal to fractional table.
+pub fn get_fraction_to_decimal_map() -> &'static StaticTable<(u32, u32), Decimal> {
+    &FRACTION_TO_DECIMAL
+}
+
+static FRACTION_TO_DECIMAL_EXTENDED: StaticTable<(u32, u32), Decimal> = StaticTable::new(&[
+    ((1, 6), dec!(1.1600)),
+    ((1, 7), dec!(1.1400)),
+    ((1, 8), dec!(1.1200)),
+    ((1, 9), dec!(1.1100)),
+    ((1, 10), dec!(1.1000)),
+    ((1, 11), dec!(1.0900)),
+    ((1, 12), dec!(1.0800)),
+    ((1, 14), dec!(1.0700)),
+    ((1, 16), dec!(1.0600)),
+    ((1, 18), dec!(1.0550)),
+    ((1, 20), dec!(1.0500)),
+    ((1, 25), dec!(1.0400)),
+    ((1, 33), dec!(1.0300)),
+    ((1, 40), dec!(1.0250)),
+    ((1, 50), dec!(1.0200)),
+    ((1, 66), dec!(1.0150)),
+    ((1, 80), dec!(1.0120)),
+    ((1, 150), dec!(1.0070)),
+    ((1, 200), dec!(1.0050)),
+    ((1, 250), dec!(1.0040)),
+    ((1, 300), dec!(1.0030)),
+    ((1, 400), dec!(1.0025)),
+    ((1, 500), dec!(1.0020)),
+    ((1, 750), dec!(1.0013)),
+    ((1, 1000), dec!(1.0010)),
+    ((2, 11), dec!(1.1800)),
+    ((2, 13), dec!(1.1500)),
+    ((2, 15), dec!(1.1300)),
+    ((3, 4), dec!(1.7500)),
+    ((3, 5), dec!(1.6000)),
+    ((4, 6), dec!(1.6600)),
+    ((6, 25), dec!(1.2400)),
+    ((7, 10), dec!(1.7000)),
+    ((7, 20), dec!(1.3500)),
+    ((8, 11), dec!(1.7200)),
+    ((8, 13), dec!(1.6100)),
+    ((8, 25), dec!(1.3200)),
+    ((9, 10), dec!(1.9000)),
+    ((9, 20), dec!(1.4500)),
+    ((11, 8), dec!(2.3700)),
+    ((11, 20), dec!(1.5500)),
+    ((12, 25), dec!(1.4800)),
+    ((13, 4), dec!(4.2500)),
+    ((13, 10), dec!(2.3000)),
+    ((13, 20), dec!(1.6500)),
+    ((13, 25), dec!(1.5200)),
+    ((13, 50), dec!(1.2600)),
+    ((14, 5), dec!(3.8000)),
+    ((14, 25), dec!(1.5600)),
+    ((15, 4), dec!(4.7500)),
+    ((15, 8), dec!(2.8700)),
+    ((16, 25), dec!(1.6400)),
+    ((17, 2), dec!(9.5000)),
+    ((17, 5), dec!(4.4000)),
+    ((17, 10), dec!(2.7000)),
+    ((17, 20), dec!(1.8500)),
+    ((17, 50), dec!(1.3400)),
+    ((18, 5), dec!(4.6000)),
+    ((19, 5), dec!(4.8000)),
+    ((19, 10), dec!(2.9000)),
+    ((19, 25), dec!(1.7600)),
+    ((19, 50), dec!(1.3800)),
+    ((19, 100), dec!(1.1900)),
+    ((20, 21), dec!(1.9500)),
+    ((20, 23), dec!(1.8600)),
+    ((21, 5), dec!(5.2000)),
+    ((21, 10), dec!(3.1000)),
+    ((21, 25), dec!(1.8400)),
+    ((21, 50), dec!(1.4200)),
+    ((21, 100), dec!(1.2100)),
+    ((22, 1), dec!(23.0000)),
+    ((22, 5), dec!(5.4000)),
+    ((22, 25), dec!(1.8800)),
+    ((23, 5), dec!(5.6000)),
+    ((23, 10), dec!(3.3000)),
+    ((23, 25), dec!(1.9200)),
+    ((23, 50), dec!(1.4600)),
+    ((23, 100), dec!(1.2300)),
+    ((24, 5), dec!(5.8000)),
+    ((24, 25), dec!(1.9600)),
+    ((26, 5), dec!(6.2000)),
+    ((26, 25), dec!(2.0400)),
+    ((27, 5), dec!(6.4000)),
+    ((27, 10), dec!(3.7000)),
+    ((27, 20), dec!(2.3500)),
+    ((27, 25), dec!(2.0800)),
+    ((27, 50), dec!(1.5400)),
+    ((27, 100), dec!(1.2700)),
+    ((28, 1), dec!(29.0000)),
+    ((28, 5), dec!(6.6000)),
+    ((28, 25), dec!(2.1200)),
+    ((29, 5), dec!(6.8000)),
+    ((29, 20), dec!(2.4500)),
+    ((29, 25), dec!(2.1600)),
+    ((29, 50), dec!(1.5800)),
+    ((30, 1), dec!(31.0000)),
+    ((31, 5), dec!(7.2000)),
+    ((31, 10), dec!(4.1000)),
+    ((31, 25), dec!(2.2400)),
+    ((31, 100), dec!(1.3100)),
+    ((32, 5), dec!(7.4000)),
+    ((32, 25), dec!(2.2800)),
+    ((33, 5), dec!(7.6000)),
+    ((33, 10), dec!(4.3000)),
+    ((33, 25), dec!(2.3200)),
+    ((34, 5), dec!(7.8000)),
+    ((34, 25), dec!(2.3600)),
+    ((34, 50), dec!(1.6800)),
+    ((35, 1), dec!(36.0000)),
+    ((36, 5), dec!(8.2000)),
+    ((36, 25), dec!(2.4400)),
+    ((37, 5), dec!(8.4000)),
+    ((37, 10), dec!(4.7000)),
+    ((37, 25), dec!(2.4800)),
+    ((37, 50), dec!(1.7400)),
+    ((37, 100), dec!(1.3700)),
+    ((38, 5), dec!(8.6000)),
+    ((38, 25), dec!(2.5200)),
+    ((39, 5), dec!(8.8000)),
+    ((39, 10), dec!(4.9000)),
+    ((39, 25), dec!(2.5600)),
+    ((39, 50), dec!(1.7800)),
+    ((39, 100), dec!(1.3900)),
+    ((40, 1), dec!(41.0000)),
+    ((40, 85), dec!(1.4700)),
+    ((41, 5), dec!(9.2000)),
+    ((41, 10), dec!(5.1000)),
+    ((41, 20), dec!(3.0500)),
+    ((41, 25), dec!(2.6400)),
+    ((41, 50), dec!(1.8200)),
+    ((41, 100), dec!(1.4100)),
+    ((42, 5), dec!(9.4000)),
+    ((42, 25), dec!(2.6800)),
+    ((43, 5), dec!(9.6000)),
+    ((43, 10), dec!(5.3000)),
+    ((43, 20), dec!(3.1500)),
+    ((43, 25), dec!(2.7200)),
+    ((43, 100), dec!(1.4300)),
+    ((44, 5), dec!(9.8000)),
+    ((44, 25), dec!(2.7600)),
+    ((45, 1), dec!(46.0000)),
+    ((46, 25), dec!(2.8400)),
+    ((47, 10), dec!(5.7000)),
+    ((47, 20), dec!(3.3500)),
+    ((47, 50), dec!(1.9400)),
+    ((48, 25), dec!(2.9200)),
+    ((49, 10), dec!(5.9000)),
+    ((49, 20), dec!(3.4500)),
+    ((49, 25), dec!(2.9600)),
+    ((49, 50), dec!(1.9800)),
+    ((49, 100), dec!(1.4900)),
+    ((51, 20), dec!(3.5500)),
+    ((51, 50), dec!(2.0200)),
+    ((51, 100), dec!(1.5100)),
+    ((53, 20), dec!(3.6500)),
+    ((53, 50), dec!(2.0600)),
+    ((55, 1), dec!(56.0000)),
+    ((57, 20), dec!(3.8500)),
+    ((57, 50), dec!(2.1400)),
+    ((59, 20), dec!(3.9500)),
+    ((59, 50), dec!(2.1800)),
+    ((59, 100), dec!(1.5900)),
+    ((60, 1), dec!(61.0000)),
+    ((61, 20), dec!(4.0500)),
+    ((61, 50), dec!(2.2200)),
+    ((63, 20), dec!(4.1500)),
+    ((63, 50), dec!(2.2600)),
+    ((63, 100), dec!(1.6300)),
+    ((67, 20), dec!(4.3500)),
+    ((67, 50), dec!(2.3400)),
+    ((69, 20), dec!(4.4500)),
+    ((69, 100), dec!(1.6900)),
+    ((70, 1), dec!(71.0000)),
+    ((71, 20), dec!(4.5500)),
+    ((71, 50), dec!(2.4200)),
+    ((71, 100), dec!(1.7100)),
+    ((73, 20), dec!(4.6500)),
+    ((73, 50), dec!(2.4600)),
+    ((75, 1), dec!(76.0000)),
+    ((77, 20), dec!(4.8500)),
+    ((77, 50), dec!(2.5400)),
+    ((77, 100), dec!(1.7700)),
+    ((79, 20), dec!(4.9500)),
+    ((79, 50), dec!(2.5800)),
+    ((79, 100), dec!(1.7900)),
+    ((80, 1), dec!(81.0000)),
+    ((81, 100), dec!(1.8100)),
+    ((83, 50), dec!(2.6600)),
+    ((85, 1), dec!(86.0000)),
+    ((85, 40), dec!(3.1250)),
+    ((87, 50), dec!(2.7400)),
+    ((87, 100), dec!(1.8700)),
+    ((89, 50), dec!(2.7800)),
+    ((89, 100), dec!(1.8900)),
+    ((90, 1), dec!(91.0000)),
+    ((91, 50), dec!(2.8200)),
+    ((93, 50), dec!(2.8600)),
+    ((93, 100), dec!(1.9300)),
+    ((95, 1), dec!(96.0000)),
+    ((97, 50), dec!(2.9400)),
+    ((97, 100), dec!(1.9700)),
+    ((99, 50), dec!(2.9800)),
+    ((99, 100), dec!(1.9900)),
+    ((101, 100), dec!(2.0100)),
+    ((103, 100), dec!(2.0300)),
+    ((107, 100), dec!(2.0700)),
+    ((109, 100), dec!(2.0900)),
+    ((110, 1), dec!(111.0000)),
+    ((111, 100), dec!(2.1100)),
+    ((113, 100), dec!(2.1300)),
+    ((117, 100), dec!(2.1700)),
+    ((119, 100), dec!(2.1900)),
+    ((120, 1), dec!(121.0000)),
+    ((121, 100), dec!(2.2100)),
+    ((123, 100), dec!(2.2300)),
+    ((125, 1), dec!(126.0000)),
+    ((127, 100), dec!(2.2700)),
+    ((130, 1), dec!(131.0000)),
+    ((140, 1), dec!(141.0000)),
+    ((150, 1), dec!(151.0000)),
+    ((175, 1), dec!(176.0000)),
+    ((200, 1), dec!(201.0000)),
+    ((225, 1), dec!(226.0000)),
+    ((250, 1), dec!(251.0000)),
+    ((275, 1), dec!(276.0000)),
+    ((300, 1), dec!(301.0000)),
+    ((400, 1), dec!(401.0000)),
+    ((500, 1), dec!(501.0000)),
+]);
+
+/// Extended lookup table for conversion from fractional to decimal.
+pub fn get_fraction_to_decimal_extended_map() -> &'static StaticTable<(u32, u32), Decimal> {
+    &FRACTION_TO_DECIMAL_EXTENDED
+}
+
+static FRACTION_TO_AMERICAN: StaticTable<(u32, u32), i32> = StaticTable::new(&[
+    ((1, 1), 100),
+    ((1, 2), -200),
+    ((1, 3), -300),
+    ((1, 4), -400),
+    ((1, 5), -500),
+    ((1, 100), -10000),
+    ((2, 1), 200),
+    ((2, 5), -250),
+    ((2, 7), -350),
+    ((2, 9), -450),
+    ((3, 1), 300),
+    ((3, 10), -333),
+    ((4, 1), 400),
+    ((4, 5), -125),
+    ((4, 6), -150),
+    ((4, 7), -175),
+    ((4, 9), -225),
+    ((4, 11), -275),
+    ((5, 1), 500),
+    ((5, 2), 250),
+    ((5, 4), 125),
+    ((5, 6), -120),
+    ((6, 1), 600),
+    ((6, 4), 150),
+    ((6, 5), 120),
+    ((7, 1), 700),
+    ((7, 2), 350),
+    ((7, 4), 175),
+    ((7, 5), 140),
+    ((8, 1), 800),
+    ((8, 5), 160),
+    ((8, 11), -138),
+    ((8, 13), -163),
+    ((8, 15), -188),
+    ((9, 1), 900),
+    ((9, 2), 450),
+    ((9, 4), 225),
+    ((9, 5), 180),
+    ((10, 1), 1000),
+    ((10, 3), 333),
+    ((10, 11), -110),
+    ((11, 1), 1100),
+    ((11, 2), 550),
+    ((11, 4), 275),
+    ((11, 5), 220),
+    ((11, 8), 138),
+    ((11, 10), 110),
+    ((12, 1), 1200),
+    ((12, 5), 240),
+    ((13, 1), 1300),
+    ((13, 2), 650),
+    ((13, 5), 260),
+    ((13, 8), 163),
+    ((14, 1), 1400),
+    ((15, 1), 1500),
+    ((15, 2), 750),
+    ((15, 8), 188),
+    ((16, 1), 1600),
+    ((16, 5), 320),
+    ((18, 1), 1800),
+    ((20, 1), 2000),
+    ((21, 20), 105),
+    ((23, 20), 115),
+    ((25, 1), 2500),
+    ((33, 1), 3300),
+    ((50, 1), 5000),
+    ((66, 1), 6600),
+    ((100, 1), 10000),
+    ((1000, 1), 100000),
+]);
+
+/// Lookup table for conversion from fractional to american, reversed american to fractional table.
+pub fn get_fraction_to_american_map() -> &'static StaticTable<(u32, u32), i32> {
+    &FRACTION_TO_AMERICAN
+}
+
+static FRACTION_TO_AMERICAN_EXTENDED: StaticTable<(u32, u32), i32> = StaticTable::new(&[]);
+
+/// Extended lookup table for conversion from fractional to american.
+pub fn get_fraction_to_american_extended_map() -> &'static StaticTable<(u32, u32), i32> {
+    &FRACTION_TO_AMERICAN_EXTENDED
+}
+
+static DECIMAL_TO_AMERICAN: StaticTable<Decimal, i32> = StaticTable::new(&[
+    (key(dec!(1.01)), -10000),
+    (key(dec!(1.2)), -500),
+    (key(dec!(1.22)), -450),
+    (key(dec!(1.25)), -400),
+    (key(dec!(1.29)), -350),
+    (key(dec!(1.3)), -333),
+    (key(dec!(1.33)), -300),
+    (key(dec!(1.36)), -275),
+    (key(dec!(1.4)), -250),
+    (key(dec!(1.44)), -225),
+    (key(dec!(1.5)), -200),
+    (key(dec!(1.53)), -188),
+    (key(dec!(1.57)), -175),
+    (key(dec!(1.62)), -163),
+    (key(dec!(1.67)), -150),
+    (key(dec!(1.73)), -138),
+    (key(dec!(1.8)), -125),
+    (key(dec!(1.83)), -120),
+    (key(dec!(1.91)), -110),
+    (key(dec!(2)), 100),
+    (key(dec!(2.05)), 105),
+    (key(dec!(2.1)), 110),
+    (key(dec!(2.15)), 115),
+    (key(dec!(2.2)), 120),
+    (key(dec!(2.25)), 125),
+    (key(dec!(2.38)), 138),
+    (key(dec!(2.4)), 140),
+    (key(dec!(2.5)), 150),
+    (key(dec!(2.6)), 160),
+    (key(dec!(2.62)), 163),
+    (key(dec!(2.75)), 175),
+    (key(dec!(2.8)), 180),
+    (key(dec!(2.88)), 188),
+    (key(dec!(3)), 200),
+    (key(dec!(3.2)), 220),
+    (key(dec!(3.25)), 225),
+    (key(dec!(3.4)), 240),
+    (key(dec!(3.5)), 250),
+    (key(dec!(3.6)), 260),
+    (key(dec!(3.75)), 275),
+    (key(dec!(4)), 300),
+    (key(dec!(4.2)), 320),
+    (key(dec!(4.33)), 333),
+    (key(dec!(4.5)), 350),
+    (key(dec!(5)), 400),
+    (key(dec!(5.5)), 450),
+    (key(dec!(6)), 500),
+    (key(dec!(6.5)), 550),
+    (key(dec!(7)), 600),
+    (key(dec!(7.5)), 650),
+    (key(dec!(8)), 700),
+    (key(dec!(8.5)), 750),
+    (key(dec!(9)), 800),
+    (key(dec!(10)), 900),
+    (key(dec!(11)), 1000),
+    (key(dec!(12)), 1100),
+    (key(dec!(13)), 1200),
+    (key(dec!(14)), 1300),
+    (key(dec!(15)), 1400),
+    (key(dec!(16)), 1500),
+    (key(dec!(17)), 1600),
+    (key(dec!(19)), 1800),
+    (key(dec!(21)), 2000),
+    (key(dec!(26)), 2500),
+    (key(dec!(34)), 3300),
+    (key(dec!(51)), 5000),
+    (key(dec!(67)), 6600),
+    (key(dec!(101)), 10000),
+    (key(dec!(1001)), 100000),
+]);
+
+/// Lookup table for conversion from decimal to american, reversed american to decimal table.
+pub fn get_decimal_to_american_map() -> &'static StaticTable<Decimal, i32> {
+    &DECIMAL_TO_AMERICAN
+}
+
+static DECIMAL_TO_AMERICAN_EXTENDED: StaticTable<Decimal, i32> = StaticTable::new(&[]);
+
+/// Extended lookup table for conversion from decimal to american.
+pub fn get_decimal_to_american_extended_map() -> &'static StaticTable<Decimal, i32> {
+    &DECIMAL_TO_AMERICAN_EXTENDED
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_sorted<K: TableKey, V>(table: &StaticTable<K, V>) {
+        assert!(table.entries.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    /// Reversed table has all values of the forward one, each mapped to one of its keys.
+    fn assert_reversed<K: TableKey + PartialEq + Copy, V: TableKey + PartialEq + Copy>(
+        forward: &StaticTable<K, V>,
+        reversed: &StaticTable<V, K>,
+    ) {
+        for (key, value) in forward.iter() {
+            let reversed_key = reversed.get(value).copied().unwrap();
+            assert!(forward.get(&reversed_key) == Some(value));
+            if forward.iter().filter(|(_, v)| *v == value).count() == 1 {
+                assert!(reversed_key == key);
+            }
+        }
+        assert_eq!(reversed.iter().count(), {
+            let mut values: Vec<_> = forward.values().filter_map(V::to_stored).collect();
+            values.sort();
+            values.dedup();
+            values.len()
+        });
+    }
+
+    #[test]
+    fn test_tables_sorted() {
+        assert_sorted(get_decimal_to_fraction_map());
+        assert_sorted(get_decimal_to_fraction_extended_map());
+        assert_sorted(get_american_to_fraction_map());
+        assert_sorted(get_american_to_fraction_extended_map());
+        assert_sorted(get_american_to_decimal_map());
+        assert_sorted(get_american_to_decimal_extended_map());
+        assert_sorted(get_fraction_to_decimal_map());
+        assert_sorted(get_fraction_to_decimal_extended_map());
+        assert_sorted(get_fraction_to_american_map());
+        assert_sorted(get_fraction_to_american_extended_map());
+        assert_sorted(get_decimal_to_american_map());
+        assert_sorted(get_decimal_to_american_extended_map());
+    }
+
+    #[test]
+    fn test_reversed_tables() {
+        assert_reversed(get_decimal_to_fraction_map(), get_fraction_to_decimal_map());
+        assert_reversed(
+            get_decimal_to_fraction_extended_map(),
+            get_fraction_to_decimal_extended_map(),
+        );
+        assert_reversed(
+            get_american_to_fraction_map(),
+            get_fraction_to_american_map(),
+        );
+        assert_reversed(
+            get_american_to_fraction_extended_map(),
+            get_fraction_to_american_extended_map(),
+        );
+        assert_reversed(get_american_to_decimal_map(), get_decimal_to_american_map());
+        assert_reversed(
+            get_american_to_decimal_extended_map(),
+            get_decimal_to_american_extended_map(),
+        );
+    }
+
+    #[test]
+    fn test_decimal_key() {
+        assert_eq!(dec!(1.91).to_stored(), Some(19100));
+        assert_eq!(dec!(1.910000).to_stored(), Some(19100));
+        assert_eq!(dec!(1001).to_stored(), Some(10010000));
+        assert_eq!(dec!(1.91001).to_stored(), None);
+        assert_eq!(dec!(-1.91).to_stored(), None);
+        assert_eq!(Decimal::MAX.to_stored(), None);
+        assert_eq!(Decimal::from_stored(19100).to_string(), "1.91");
+        assert_eq!(key(dec!(1.0013)), 10013);
+
+        let table = get_decimal_to_fraction_map();
+        assert_eq!(table.get(&dec!(1.91)), Some(&(10, 11)));
+        assert_eq!(table.get(&dec!(1.9100)), Some(&(10, 11)));
+        assert_eq!(table.get(&dec!(1.92)), None);
+        assert_eq!(table.get(&dec!(0.5)), None);
+        assert_eq!(table.iter().next(), Some((dec!(1.01), &(1, 100))));
+    }
 }
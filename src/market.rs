@@ -0,0 +1,105 @@
+use crate::Odds;
+
+/// Runners with the shortest price in the market, by their indices in market order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Favourite {
+    /// Single runner with the shortest price.
+    Sole(usize),
+    /// Two runners sharing the shortest price.
+    Joint([usize; 2]),
+    /// More than two runners sharing the shortest price.
+    Co(Vec<usize>),
+}
+
+impl Favourite {
+    pub fn runners(&self) -> &[usize] {
+        match self {
+            Favourite::Sole(index) => std::slice::from_ref(index),
+            Favourite::Joint(indices) => indices,
+            Favourite::Co(indices) => indices,
+        }
+    }
+}
+
+/// Find the favourite of the market, `None` if it's empty.
+///
+/// Prices are compared exactly in any format, so 4/6 and -150 are joint favourites, while 1.67 is not.
+pub fn favourite(market: &[Odds]) -> Option<Favourite> {
+    // Shorter price is greater
+    let shortest = market.iter().max()?;
+    let runners: Vec<_> = market
+        .iter()
+        .enumerate()
+        .filter(|(_, odds)| *odds == shortest)
+        .map(|(index, _)| index)
+        .collect();
+
+    Some(match runners[..] {
+        [index] => Favourite::Sole(index),
+        [first, second] => Favourite::Joint([first, second]),
+        _ => Favourite::Co(runners),
+    })
+}
+
+/// Rank of each runner by price, from 1 for the favourite.
+///
+/// Runners with equal prices share the rank and the next rank is skipped, f. ex. joint favourites
+/// are both ranked 1 and the next runner 3.
+pub fn price_ranks(market: &[Odds]) -> Vec<usize> {
+    market
+        .iter()
+        .map(|odds| 1 + market.iter().filter(|other| *other > odds).count())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_favourite() {
+        let market = [
+            Odds::fractional(5, 1).unwrap(),
+            Odds::decimal(dec!(1.67)).unwrap(),
+            Odds::american(-150).unwrap(),
+            Odds::fractional(7, 2).unwrap(),
+        ];
+        let sole = favourite(&market).unwrap();
+        assert_eq!(sole, Favourite::Sole(2));
+        assert_eq!(sole.runners(), [2]);
+
+        let market = [
+            Odds::fractional(4, 6).unwrap(),
+            Odds::decimal(dec!(1.67)).unwrap(),
+            Odds::american(-150).unwrap(),
+        ];
+        assert_eq!(favourite(&market), Some(Favourite::Joint([0, 2])));
+
+        let market = [
+            Odds::fractional(2, 1).unwrap(),
+            Odds::decimal(dec!(3)).unwrap(),
+            Odds::american(200).unwrap(),
+            Odds::fractional(4, 1).unwrap(),
+        ];
+        let co = favourite(&market).unwrap();
+        assert_eq!(co, Favourite::Co(vec![0, 1, 2]));
+        assert_eq!(co.runners(), [0, 1, 2]);
+
+        assert_eq!(favourite(&[]), None);
+    }
+
+    #[test]
+    fn test_price_ranks() {
+        let market = [
+            Odds::fractional(5, 1).unwrap(),
+            Odds::fractional(6, 4).unwrap(),
+            Odds::american(150).unwrap(),
+            Odds::decimal(dec!(4.5)).unwrap(),
+            Odds::decimal(dec!(6.00)).unwrap(),
+        ];
+        assert_eq!(price_ranks(&market), [4, 1, 1, 3, 4]);
+        assert!(price_ranks(&[]).is_empty());
+    }
+}
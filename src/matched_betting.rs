@@ -0,0 +1,229 @@
+use rust_decimal::Decimal;
+
+use crate::{CalculationError, Odds};
+
+/// Kind of the back bet being covered by a lay bet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackBetKind {
+    /// Regular cash bet, stake is lost when the bet loses.
+    Qualifying,
+    /// Free bet where the stake is not returned with the winnings (SNR).
+    FreeBetStakeNotReturned,
+    /// Free bet where the stake is returned with the winnings (SR).
+    FreeBetStakeReturned,
+}
+
+/// Result of covering a back bet with a lay bet at an exchange.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LayBet {
+    /// Stake to place on the lay side.
+    pub lay_stake: Decimal,
+    /// Amount lost on the exchange when the back bet wins.
+    pub liability: Decimal,
+    /// Net result when the back bet wins.
+    pub profit_if_back_wins: Decimal,
+    /// Net result when the lay bet wins (after commission).
+    pub profit_if_lay_wins: Decimal,
+}
+
+/// Compute the lay stake which gives the same result whichever side wins.
+///
+/// Commission is given as a fraction of the lay winnings, f. ex. `0.02` for 2%.
+/// Odds in any format are accepted, they are converted to decimal without lookup tables.
+pub fn optimal_lay_stake(
+    kind: BackBetKind,
+    back_odds: &Odds,
+    back_stake: Decimal,
+    lay_odds: &Odds,
+    commission: Decimal,
+) -> Result<Decimal, CalculationError> {
+    if back_stake < Decimal::ZERO {
+        return Err(CalculationError::NegativeStake);
+    }
+    if commission < Decimal::ZERO || commission >= Decimal::ONE {
+        return Err(CalculationError::InvalidCommission);
+    }
+
+    let back = back_odds.exact_decimal();
+    let lay = lay_odds.exact_decimal();
+
+    let back_return = match kind {
+        BackBetKind::Qualifying | BackBetKind::FreeBetStakeReturned => back * back_stake,
+        BackBetKind::FreeBetStakeNotReturned => (back - Decimal::ONE) * back_stake,
+    };
+
+    Ok(back_return / (lay - commission))
+}
+
+/// Compute the lay bet covering the back bet, with outcomes for both sides.
+pub fn lay_bet(
+    kind: BackBetKind,
+    back_odds: &Odds,
+    back_stake: Decimal,
+    lay_odds: &Odds,
+    commission: Decimal,
+) -> Result<LayBet, CalculationError> {
+    let lay_stake = optimal_lay_stake(kind, back_odds, back_stake, lay_odds, commission)?;
+
+    let back = back_odds.exact_decimal();
+    let lay = lay_odds.exact_decimal();
+
+    let liability = lay_stake * (lay - Decimal::ONE);
+    let lay_winnings = lay_stake * (Decimal::ONE - commission);
+
+    let (profit_if_back_wins, profit_if_lay_wins) = match kind {
+        BackBetKind::Qualifying => (
+            back_stake * (back - Decimal::ONE) - liability,
+            lay_winnings - back_stake,
+        ),
+        BackBetKind::FreeBetStakeNotReturned => {
+            (back_stake * (back - Decimal::ONE) - liability, lay_winnings)
+        }
+        BackBetKind::FreeBetStakeReturned => (back_stake * back - liability, lay_winnings),
+    };
+
+    Ok(LayBet {
+        lay_stake,
+        liability,
+        profit_if_back_wins,
+        profit_if_lay_wins,
+    })
+}
+
+/// Compute the loss of a qualifying bet covered with an optimal lay bet.
+///
+/// Positive value means a loss, negative value means the bet is an arbitrage.
+pub fn qualifying_loss(
+    back_odds: &Odds,
+    back_stake: Decimal,
+    lay_odds: &Odds,
+    commission: Decimal,
+) -> Result<Decimal, CalculationError> {
+    lay_bet(
+        BackBetKind::Qualifying,
+        back_odds,
+        back_stake,
+        lay_odds,
+        commission,
+    )
+    .map(|bet| -bet.profit_if_lay_wins)
+}
+
+/// Compute the guaranteed profit extracted from a free bet covered with an optimal lay bet.
+pub fn free_bet_value(
+    kind: BackBetKind,
+    back_odds: &Odds,
+    free_bet_stake: Decimal,
+    lay_odds: &Odds,
+    commission: Decimal,
+) -> Result<Decimal, CalculationError> {
+    lay_bet(kind, back_odds, free_bet_stake, lay_odds, commission).map(|bet| bet.profit_if_lay_wins)
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use crate::testing_helpers::assert_decimal_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_qualifying_bet() {
+        let back = Odds::fractional(3, 1).unwrap();
+        let lay = Odds::decimal(dec!(4.2)).unwrap();
+
+        let bet = lay_bet(BackBetKind::Qualifying, &back, dec!(10), &lay, dec!(0.02)).unwrap();
+
+        // 10 * 4 / (4.2 - 0.02)
+        assert_decimal_eq(bet.lay_stake, dec!(9.5694));
+        assert_decimal_eq(bet.liability, dec!(30.6220));
+        assert_decimal_eq(bet.profit_if_back_wins, bet.profit_if_lay_wins);
+        assert_decimal_eq(bet.profit_if_lay_wins, dec!(-0.6220));
+
+        assert_decimal_eq(
+            qualifying_loss(&back, dec!(10), &lay, dec!(0.02)).unwrap(),
+            dec!(0.6220),
+        );
+
+        // Laying lower than backing without commission is an arbitrage
+        assert_decimal_eq(
+            qualifying_loss(
+                &back,
+                dec!(10),
+                &Odds::decimal(dec!(3.8)).unwrap(),
+                Decimal::ZERO,
+            )
+            .unwrap(),
+            dec!(-0.5263),
+        );
+    }
+
+    #[test]
+    fn test_free_bet_snr() {
+        // Bookmaker price entered in fractional
+        let back = Odds::fractional(5, 1).unwrap();
+        let lay = Odds::decimal(dec!(6.4)).unwrap();
+
+        let bet = lay_bet(
+            BackBetKind::FreeBetStakeNotReturned,
+            &back,
+            dec!(20),
+            &lay,
+            dec!(0.05),
+        )
+        .unwrap();
+
+        // 20 * 5 / (6.4 - 0.05)
+        assert_decimal_eq(bet.lay_stake, dec!(15.7480));
+        assert_decimal_eq(bet.profit_if_back_wins, bet.profit_if_lay_wins);
+        assert_decimal_eq(bet.profit_if_lay_wins, dec!(14.9606));
+
+        assert_decimal_eq(
+            free_bet_value(
+                BackBetKind::FreeBetStakeNotReturned,
+                &back,
+                dec!(20),
+                &lay,
+                dec!(0.05),
+            )
+            .unwrap(),
+            dec!(14.9606),
+        );
+    }
+
+    #[test]
+    fn test_free_bet_sr() {
+        let back = Odds::american(400).unwrap();
+        let lay = Odds::decimal(dec!(5.5)).unwrap();
+
+        let bet = lay_bet(
+            BackBetKind::FreeBetStakeReturned,
+            &back,
+            dec!(10),
+            &lay,
+            dec!(0.02),
+        )
+        .unwrap();
+
+        // 10 * 5 / (5.5 - 0.02)
+        assert_decimal_eq(bet.lay_stake, dec!(9.1241));
+        assert_decimal_eq(bet.profit_if_back_wins, bet.profit_if_lay_wins);
+        assert_decimal_eq(bet.profit_if_lay_wins, dec!(8.9416));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let back = Odds::decimal(dec!(2.0)).unwrap();
+        let lay = Odds::decimal(dec!(2.1)).unwrap();
+
+        assert_eq!(
+            optimal_lay_stake(BackBetKind::Qualifying, &back, dec!(-1), &lay, dec!(0.02)),
+            Err(CalculationError::NegativeStake)
+        );
+        assert_eq!(
+            optimal_lay_stake(BackBetKind::Qualifying, &back, dec!(10), &lay, dec!(1)),
+            Err(CalculationError::InvalidCommission)
+        );
+    }
+}
@@ -1,98 +1,427 @@
-use rust_decimal::Decimal;
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use rust_decimal::{Decimal, prelude::ToPrimitive};
 
 use crate::{
-    ConversionConfig, ConversionError, american_to_decimal_custom, american_to_fractional_custom,
-    decimal_to_american_custom, decimal_to_fractional_custom, fractional_to_american_custom,
-    fractional_to_decimal,
+    AmericanOdds, ConversionConfig, ConversionError, DecimalOdds, Fraction, FractionalOdds,
+    PreciseAmericanOdds, american_to_decimal_custom, american_to_fractional_custom,
+    convert::{
+        fractional_to_precise_american_custom, precise_american_to_american_custom,
+        precise_american_to_fractional_custom,
+    },
+    decimal_to_american_custom, decimal_to_fractional_custom, decimal_to_precise_american_custom,
+    fractional_to_american_custom, fractional_to_decimal_custom,
+    precise_american_to_decimal_custom,
+    registry::with_default_config,
 };
 
-#[derive(Clone, Copy)]
+/// Odds in any of the formats. Values are validated, so invalid odds cannot be represented.
+///
+/// Odds are compared by implied probability regardless of the format, so `4/6` equals `-150` and
+/// is greater than `2.0`. Equality and ordering are exact (decimal odds are compared as rationals),
+/// so `1.6667` is not equal to `4/6` - use [`Odds::approx_eq`] or [`Odds::approx_cmp`] for that.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "format", content = "value", rename_all = "lowercase")
+)]
 pub enum Odds {
-    American(i32),
-    Decimal(Decimal),
-    Fractional { num: u32, den: u32 },
+    American(AmericanOdds),
+    #[cfg_attr(feature = "serde", serde(rename = "precise_american"))]
+    PreciseAmerican(PreciseAmericanOdds),
+    Decimal(DecimalOdds),
+    Fractional(FractionalOdds),
 }
 
-impl From<i32> for Odds {
-    fn from(value: i32) -> Self {
+impl From<AmericanOdds> for Odds {
+    fn from(value: AmericanOdds) -> Self {
         Self::American(value)
     }
 }
 
-impl From<Decimal> for Odds {
-    fn from(value: Decimal) -> Self {
+impl From<PreciseAmericanOdds> for Odds {
+    fn from(value: PreciseAmericanOdds) -> Self {
+        Self::PreciseAmerican(value)
+    }
+}
+
+impl From<DecimalOdds> for Odds {
+    fn from(value: DecimalOdds) -> Self {
         Self::Decimal(value)
     }
 }
 
-impl From<(u32, u32)> for Odds {
-    fn from((num, den): (u32, u32)) -> Self {
-        Self::Fractional { num, den }
+impl From<FractionalOdds> for Odds {
+    fn from(value: FractionalOdds) -> Self {
+        Self::Fractional(value)
+    }
+}
+
+impl TryFrom<i32> for Odds {
+    type Error = ConversionError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        Self::american(value)
+    }
+}
+
+impl TryFrom<Decimal> for Odds {
+    type Error = ConversionError;
+
+    fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+        Self::decimal(value)
+    }
+}
+
+impl TryFrom<(u32, u32)> for Odds {
+    type Error = ConversionError;
+
+    fn try_from((num, den): (u32, u32)) -> Result<Self, Self::Error> {
+        Self::fractional(num, den)
+    }
+}
+
+/// Whole precise american odds are converted like `i32` ones, so lookup tables are used for them.
+fn precise_american_whole(american: &PreciseAmericanOdds) -> Option<i32> {
+    let value = american.value();
+    if value.fract().is_zero() {
+        value.to_i32()
+    } else {
+        None
+    }
+}
+
+/// Exact decimal price of american odds (at least 100 in absolute value) as `(num, den)`.
+pub(crate) fn american_ratio(value: Decimal) -> (u128, u128) {
+    // Scale is at most 28, so this fits in u128
+    let (mantissa, scale) = (value.mantissa().unsigned_abs(), 10u128.pow(value.scale()));
+    if value > Decimal::ZERO {
+        (mantissa + 100 * scale, 100 * scale)
+    } else {
+        (mantissa + 100 * scale, mantissa)
+    }
+}
+
+/// Exact positive decimal odds as `(num, den)`.
+pub(crate) fn decimal_ratio(value: Decimal) -> (u128, u128) {
+    // Scale is at most 28 so the power fits in u128
+    (value.mantissa().unsigned_abs(), 10u128.pow(value.scale()))
+}
+
+/// Exact decimal price of fractional odds as `(num, den)`.
+pub(crate) fn fraction_ratio(num: u32, den: u32) -> (u128, u128) {
+    let (num, den) = (u128::from(num), u128::from(den));
+    (num + den, den)
+}
+
+/// Compare positive fractions `a / b` and `c / d` without multiplying, so it cannot overflow.
+pub(crate) fn cmp_ratios((mut a, mut b): (u128, u128), (mut c, mut d): (u128, u128)) -> Ordering {
+    let mut reversed = false;
+
+    loop {
+        let (r1, r2) = (a % b, c % d);
+        let ordering = match ((a / b).cmp(&(c / d)), r1, r2) {
+            (Ordering::Equal, 0, 0) => Ordering::Equal,
+            (Ordering::Equal, 0, _) => Ordering::Less,
+            (Ordering::Equal, _, 0) => Ordering::Greater,
+            (Ordering::Equal, _, _) => {
+                // Same whole part, r1 / b < r2 / d if and only if b / r1 > d / r2
+                (a, b, c, d) = (b, r1, d, r2);
+                reversed = !reversed;
+                continue;
+            }
+            (ordering, _, _) => ordering,
+        };
+
+        return if reversed {
+            ordering.reverse()
+        } else {
+            ordering
+        };
+    }
+}
+
+impl PartialEq for Odds {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Odds {}
+
+impl PartialOrd for Odds {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Odds {
+    /// Exact comparison of implied probabilities - longer price is less than shorter one.
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Bigger decimal price means smaller implied probability
+        cmp_ratios(other.decimal_ratio(), self.decimal_ratio())
+    }
+}
+
+impl Hash for Odds {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Reduced fraction is the same for all exactly equal odds
+        self.decimal_ratio().hash(state);
+    }
+}
+
+impl TryFrom<Fraction> for Odds {
+    type Error = ConversionError;
+
+    fn try_from(value: Fraction) -> Result<Self, Self::Error> {
+        FractionalOdds::try_from(value).map(Self::Fractional)
+    }
+}
+
+impl fmt::Display for Odds {
+    /// Compact format: `+150` / `-110` for american, `3.50` for decimal and `5/2` for fractional.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Odds::American(american) => american.fmt(f),
+            Odds::PreciseAmerican(american) => american.fmt(f),
+            Odds::Decimal(decimal) => decimal.fmt(f),
+            Odds::Fractional(fractional) => fractional.fmt(f),
+        }
+    }
+}
+
+/// Error returned when parsing odds from a string.
+#[derive(Debug, PartialEq)]
+pub enum ParseOddsError {
+    /// String is not in any of the supported formats.
+    InvalidFormat,
+    /// String was parsed, but the value is not valid odds.
+    InvalidOdds(ConversionError),
+}
+
+impl fmt::Display for ParseOddsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseOddsError::InvalidFormat => write!(f, "invalid odds format"),
+            ParseOddsError::InvalidOdds(err) => write!(f, "invalid odds value: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseOddsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseOddsError::InvalidFormat => None,
+            ParseOddsError::InvalidOdds(err) => Some(err),
+        }
+    }
+}
+
+impl FromStr for Odds {
+    type Err = ParseOddsError;
+
+    /// Parse compact format: american must be signed (`+150`, `-110`, `-112.5`), fractional is separated with slash (`5/2`),
+    /// anything else is parsed as decimal (`3.50`). Parsed value is validated.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let odds = if let Some((num, den)) = s.split_once('/') {
+            let num = num
+                .trim()
+                .parse()
+                .map_err(|_| ParseOddsError::InvalidFormat)?;
+            let den = den
+                .trim()
+                .parse()
+                .map_err(|_| ParseOddsError::InvalidFormat)?;
+            Odds::fractional(num, den)
+        } else if s.starts_with(['+', '-']) && s.contains('.') {
+            Odds::precise_american(s.parse().map_err(|_| ParseOddsError::InvalidFormat)?)
+        } else if s.starts_with(['+', '-']) {
+            Odds::american(s.parse().map_err(|_| ParseOddsError::InvalidFormat)?)
+        } else {
+            Odds::decimal(s.parse().map_err(|_| ParseOddsError::InvalidFormat)?)
+        };
+
+        odds.map_err(ParseOddsError::InvalidOdds)
     }
 }
 
 impl Odds {
+    /// Create validated american odds.
+    pub fn american(value: i32) -> Result<Self, ConversionError> {
+        AmericanOdds::new(value).map(Self::American)
+    }
+
+    /// Create validated precise (decimal-valued) american odds, f. ex. -112.5.
+    pub fn precise_american(value: Decimal) -> Result<Self, ConversionError> {
+        PreciseAmericanOdds::new(value).map(Self::PreciseAmerican)
+    }
+
+    /// Create validated decimal odds.
+    pub fn decimal(value: Decimal) -> Result<Self, ConversionError> {
+        DecimalOdds::new(value).map(Self::Decimal)
+    }
+
+    /// Create validated fractional odds.
+    pub fn fractional(num: u32, den: u32) -> Result<Self, ConversionError> {
+        FractionalOdds::new(num, den).map(Self::Fractional)
+    }
+
     /// Convert from decimal or fractional to american using default parameters. If already american, just return the value.
     pub fn to_american(&self) -> Result<i32, ConversionError> {
-        self.to_american_custom(&ConversionConfig::default())
+        with_default_config(|config| self.to_american_custom(config))
     }
 
     /// Convert from decimal or fractional to american using custom parameters. If already american, just return the value.
     pub fn to_american_custom(&self, config: &ConversionConfig) -> Result<i32, ConversionError> {
         match self {
-            Odds::American(inner) => Ok(*inner),
-            Odds::Decimal(decimal) => decimal_to_american_custom(*decimal, config),
-            Odds::Fractional { num, den } => fractional_to_american_custom(*num, *den, config),
+            Odds::American(american) => Ok(american.value()),
+            Odds::PreciseAmerican(american) => {
+                precise_american_to_american_custom(american.value(), config)
+            }
+            Odds::Decimal(decimal) => decimal_to_american_custom(decimal.value(), config),
+            Odds::Fractional(fractional) => {
+                fractional_to_american_custom(fractional.num(), fractional.den(), config)
+            }
+        }
+    }
+
+    /// Convert to precise american odds using default parameters. If already american, just return the value.
+    pub fn to_precise_american(&self) -> Result<Decimal, ConversionError> {
+        with_default_config(|config| self.to_precise_american_custom(config))
+    }
+
+    /// Convert to precise american odds using custom parameters, rounded to `american_step` from the config.
+    /// If already american, just return the value.
+    pub fn to_precise_american_custom(
+        &self,
+        config: &ConversionConfig,
+    ) -> Result<Decimal, ConversionError> {
+        match self {
+            Odds::American(american) => Ok(Decimal::from(american.value())),
+            Odds::PreciseAmerican(american) => Ok(american.value()),
+            Odds::Decimal(decimal) => decimal_to_precise_american_custom(decimal.value(), config),
+            Odds::Fractional(fractional) => {
+                fractional_to_precise_american_custom(fractional.num(), fractional.den(), config)
+            }
         }
     }
 
     /// Convert from american or decimal to fractional using default parameters. If already fractional, just return the value.
-    pub fn to_fractional(&self) -> Result<(u32, u32), ConversionError> {
-        self.to_fractional_custom(&ConversionConfig::default())
+    pub fn to_fractional(&self) -> Result<Fraction, ConversionError> {
+        with_default_config(|config| self.to_fractional_custom(config))
     }
 
     /// Convert from american or decimal to fractional using custom parameters. If already fractional, just return the value.
     pub fn to_fractional_custom(
         &self,
         config: &ConversionConfig,
-    ) -> Result<(u32, u32), ConversionError> {
+    ) -> Result<Fraction, ConversionError> {
         match self {
-            Odds::American(inner) => american_to_fractional_custom(*inner, config),
-            Odds::Decimal(decimal) => decimal_to_fractional_custom(*decimal, config),
-            Odds::Fractional { num, den } => {
-                if *den > 0 {
-                    Ok((*num, *den))
-                } else {
-                    Err(ConversionError::DenominatorZero)
-                }
-            }
+            Odds::American(american) => american_to_fractional_custom(american.value(), config),
+            Odds::PreciseAmerican(american) => match precise_american_whole(american) {
+                Some(whole) => american_to_fractional_custom(whole, config),
+                None => precise_american_to_fractional_custom(american.value(), config),
+            },
+            Odds::Decimal(decimal) => decimal_to_fractional_custom(decimal.value(), config),
+            Odds::Fractional(fractional) => Ok((*fractional).into()),
         }
     }
 
     /// Convert from american or fractional to decimal using default parameters. If already decimal, just return the value.
     pub fn to_decimal(&self) -> Result<Decimal, ConversionError> {
-        self.to_decimal_custom(&ConversionConfig::default())
+        with_default_config(|config| self.to_decimal_custom(config))
     }
 
     /// Convert from american or fractional to decimal using custom parameters. If already decimal, just return the value.
     pub fn to_decimal_custom(&self, config: &ConversionConfig) -> Result<Decimal, ConversionError> {
         match self {
-            Odds::American(inner) => american_to_decimal_custom(*inner, config),
-            Odds::Decimal(decimal) => {
-                if *decimal > Decimal::ONE {
-                    Ok(*decimal)
-                } else {
-                    Err(ConversionError::InvalidDecimal)
-                }
+            Odds::American(american) => american_to_decimal_custom(american.value(), config),
+            Odds::PreciseAmerican(american) => {
+                precise_american_to_decimal_custom(american.value(), config)
+            }
+            Odds::Decimal(decimal) => Ok(decimal.value()),
+            Odds::Fractional(fractional) => {
+                fractional_to_decimal_custom(fractional.num(), fractional.den(), config)
             }
-            Odds::Fractional { num, den } => fractional_to_decimal(*num, *den),
         }
     }
 
+    /// Convert to decimal without using lookup tables, so the value is exact
+    /// rather than rounded for display. Used by the betting calculators.
+    pub(crate) fn exact_decimal(&self) -> Decimal {
+        match self {
+            Odds::American(american) => DecimalOdds::from(*american).value(),
+            Odds::PreciseAmerican(american) => DecimalOdds::from(*american).value(),
+            Odds::Decimal(decimal) => decimal.value(),
+            Odds::Fractional(fractional) => DecimalOdds::from(*fractional).value(),
+        }
+    }
+
+    /// Probability implied by the price (without removing bookmaker's margin).
+    pub fn implied_probability(&self) -> Decimal {
+        Decimal::ONE / self.exact_decimal()
+    }
+
+    /// Check if the price is shorter than evens, f. ex. 4/6, 1.5 or -150.
+    pub fn is_odds_on(&self) -> bool {
+        self.cmp_evens() == Ordering::Less
+    }
+
+    /// Check if the price is exactly evens: 1/1, 2.0, +100 or -100.
+    pub fn is_evens(&self) -> bool {
+        self.cmp_evens() == Ordering::Equal
+    }
+
+    /// Check if the price is longer than evens, f. ex. 6/4, 2.5 or +150.
+    pub fn is_odds_against(&self) -> bool {
+        self.cmp_evens() == Ordering::Greater
+    }
+
+    /// Compare decimal price with 2.0 exactly.
+    fn cmp_evens(&self) -> Ordering {
+        cmp_ratios(self.decimal_ratio(), (2, 1))
+    }
+
+    /// Decimal price as a reduced fraction `(numerator, denominator)`.
+    pub(crate) fn decimal_ratio(&self) -> (u128, u128) {
+        let (num, den) = match self {
+            Odds::American(american) => american_ratio(Decimal::from(american.value())),
+            Odds::PreciseAmerican(american) => american_ratio(american.value()),
+            Odds::Decimal(decimal) => decimal_ratio(decimal.value()),
+            Odds::Fractional(fractional) => fraction_ratio(fractional.num(), fractional.den()),
+        };
+
+        let divisor = num_integer::gcd(num, den);
+        (num / divisor, den / divisor)
+    }
+
+    /// Compare implied probabilities, treating them as equal if they differ by at most `tolerance`.
+    pub fn approx_cmp(&self, other: &Odds, tolerance: Decimal) -> Ordering {
+        let (p1, p2) = (self.implied_probability(), other.implied_probability());
+
+        if (p1 - p2).abs() <= tolerance {
+            Ordering::Equal
+        } else {
+            p1.cmp(&p2)
+        }
+    }
+
+    /// Check if implied probabilities differ by at most `tolerance`, f. ex. `1.67` and `4/6` with `0.001`.
+    pub fn approx_eq(&self, other: &Odds, tolerance: Decimal) -> bool {
+        self.approx_cmp(other, tolerance) == Ordering::Equal
+    }
+
     /// Convert from american or decimal to fractional using default parameters
     /// (if already fractional, just take the value) and format to string.
     pub fn to_fractional_str(&self) -> Result<String, ConversionError> {
-        self.to_fractional_str_custom(&ConversionConfig::default())
+        with_default_config(|config| self.to_fractional_str_custom(config))
     }
 
     /// Convert from american or decimal to fractional using custom parameters
@@ -101,14 +430,13 @@ impl Odds {
         &self,
         config: &ConversionConfig,
     ) -> Result<String, ConversionError> {
-        let (num, den) = self.to_fractional_custom(config)?;
-        Ok(format!("{num}/{den}"))
+        Ok(self.to_fractional_custom(config)?.to_string())
     }
 
     /// Convert from american or fractional to decimal using default parameters
     /// (if already decimal, just take the value) and format to string.
     pub fn to_decimal_str(&self) -> Result<String, ConversionError> {
-        self.to_decimal_str_custom(&ConversionConfig::default())
+        with_default_config(|config| self.to_decimal_str_custom(config))
     }
 
     /// Convert from american or fractional to decimal using custom parameters
@@ -127,7 +455,7 @@ impl Odds {
 
 #[cfg(test)]
 mod tests {
-    use crate::testing_helpers::assert_decimal_ok_eq;
+    use crate::testing_helpers::{assert_decimal_eq, assert_decimal_ok_eq, frac};
 
     use super::*;
     use rust_decimal_macros::dec;
@@ -136,174 +464,438 @@ mod tests {
 
     #[test]
     fn test_from_primitives_to_odds() {
-        // From<i32>
-        if let Odds::American(val) = Odds::from(150) {
-            assert_eq!(val, 150);
+        // TryFrom<i32>
+        if let Odds::American(val) = Odds::try_from(150).unwrap() {
+            assert_eq!(val.value(), 150);
         } else {
             panic!("Expected Odds::American");
         }
 
-        // From<Decimal>
-        if let Odds::Decimal(val) = Odds::from(dec!(3.33)) {
-            assert_eq!(val, dec!(3.33));
+        // TryFrom<Decimal>
+        if let Odds::Decimal(val) = Odds::try_from(dec!(3.33)).unwrap() {
+            assert_eq!(val.value(), dec!(3.33));
         } else {
             panic!("Expected Odds::Decimal");
         }
 
-        // From<(u32, u32)>
-        if let Odds::Fractional { num, den } = Odds::from((7, 2)) {
-            assert_eq!(num, 7);
-            assert_eq!(den, 2);
+        // TryFrom<(u32, u32)>
+        if let Odds::Fractional(val) = Odds::try_from((7, 2)).unwrap() {
+            assert_eq!(val.num(), 7);
+            assert_eq!(val.den(), 2);
         } else {
             panic!("Expected Odds::Fractional");
         }
+
+        // Invalid values are rejected
+        assert_eq!(Odds::try_from(0).err(), Some(ConversionError::AmericanZero));
+        assert_eq!(
+            Odds::american(-99).err(),
+            Some(ConversionError::InvalidAmerican(-99))
+        );
+        assert_eq!(
+            Odds::try_from(dec!(1.0)).err(),
+            Some(ConversionError::InvalidDecimal(dec!(1.0)))
+        );
+        assert_eq!(
+            Odds::fractional(1, 0).err(),
+            Some(ConversionError::DenominatorZero { num: 1 })
+        );
+
+        // From validated newtypes
+        assert_eq!(
+            Odds::from(AmericanOdds::new(-110).unwrap()).to_american(),
+            Ok(-110)
+        );
     }
 
     #[test]
     fn test_from_odds_to_i32() {
-        let american = Odds::American(-110);
-        let decimal = Odds::Decimal(dec!(3.5));
-        let fractional = Odds::Fractional { num: 9, den: 1 };
-        let invalid_decimal = Odds::Decimal(dec!(1.0));
+        let american = Odds::american(-110).unwrap();
+        let decimal = Odds::decimal(dec!(3.5)).unwrap();
+        let fractional = Odds::fractional(9, 1).unwrap();
 
         assert_eq!(american.to_american(), Ok(-110));
         assert_eq!(decimal.to_american(), Ok(250));
         assert_eq!(fractional.to_american(), Ok(900));
-        assert_eq!(
-            invalid_decimal.to_american(),
-            Err(ConversionError::InvalidDecimal)
-        );
     }
 
     #[test]
     fn test_from_odds_to_fractional_tuple() {
-        let american = Odds::American(-150);
-        let decimal = Odds::Decimal(dec!(1.25));
-        let fractional = Odds::Fractional { num: 9, den: 1 };
-        let invalid_american = Odds::American(0);
+        let american = Odds::american(-150).unwrap();
+        let decimal = Odds::decimal(dec!(1.25)).unwrap();
+        let fractional = Odds::fractional(9, 1).unwrap();
 
-        assert_eq!(american.to_fractional(), Ok((4, 6)));
+        assert_eq!(american.to_fractional(), Ok(frac(4, 6)));
 
         assert_eq!(
             american.to_fractional_custom(&ConversionConfig::default().no_lookup()),
-            Ok((2, 3))
+            Ok(frac(2, 3))
         );
 
-        assert_eq!(decimal.to_fractional(), Ok((1, 4)));
-        assert_eq!(fractional.to_fractional(), Ok((9, 1)));
-
-        assert_eq!(
-            invalid_american.to_fractional(),
-            Err(ConversionError::AmericanZero)
-        );
+        assert_eq!(decimal.to_fractional(), Ok(frac(1, 4)));
+        assert_eq!(fractional.to_fractional(), Ok(frac(9, 1)));
     }
 
     #[test]
     fn test_from_odds_to_decimal() {
-        let american = Odds::American(200);
-        let decimal = Odds::Decimal(dec!(1.75));
-        let fractional = Odds::Fractional { num: 1, den: 2 };
-        let invalid_american = Odds::American(0);
+        let american = Odds::american(200).unwrap();
+        let decimal = Odds::decimal(dec!(1.75)).unwrap();
+        let fractional = Odds::fractional(1, 2).unwrap();
 
         assert_decimal_ok_eq(american.to_decimal(), dec!(3.0));
         assert_decimal_ok_eq(decimal.to_decimal(), dec!(1.75));
         assert_decimal_ok_eq(fractional.to_decimal(), dec!(1.5));
+    }
+
+    #[test]
+    fn test_implied_probability() {
+        assert_decimal_eq(
+            Odds::decimal(dec!(2.0)).unwrap().implied_probability(),
+            dec!(0.5),
+        );
+        assert_decimal_eq(
+            Odds::fractional(4, 6).unwrap().implied_probability(),
+            dec!(0.6),
+        );
+        // No lookup, -150 is exactly 60%
+        assert_decimal_eq(
+            Odds::american(-150).unwrap().implied_probability(),
+            dec!(0.6),
+        );
+        assert_decimal_eq(
+            Odds::american(300).unwrap().implied_probability(),
+            dec!(0.25),
+        );
+    }
+
+    #[test]
+    fn test_precise_american() {
+        let precise = Odds::precise_american(dec!(-112.5)).unwrap();
+
+        assert_eq!(precise.to_string(), "-112.5");
+        assert_eq!(precise.to_precise_american(), Ok(dec!(-112.5)));
+        assert_eq!(precise.to_american(), Ok(-113));
+        assert_eq!(
+            precise.to_american_custom(
+                &ConversionConfig::default()
+                    .rounding_strategy(rust_decimal::RoundingStrategy::MidpointTowardZero)
+            ),
+            Ok(-112)
+        );
+        assert_eq!(
+            precise.to_american_custom(&ConversionConfig::default().american_step(dec!(5))),
+            Ok(-115)
+        );
+        assert_decimal_ok_eq(precise.to_decimal(), dec!(1.8889));
+        // 100/112.5 is 8/9
+        assert_eq!(
+            precise.to_fractional_custom(&ConversionConfig::default().no_lookup()),
+            Ok(frac(8, 9))
+        );
+
+        // Exactly equal to 17/9
+        assert_eq!(precise, Odds::fractional(8, 9).unwrap());
+
+        // Whole values behave like i32 american odds
+        let whole = Odds::precise_american(dec!(-150)).unwrap();
+        assert_eq!(whole.to_fractional(), Ok(frac(4, 6)));
+        assert_eq!(whole, Odds::american(-150).unwrap());
+
+        // Other formats converted using american step
+        let config = ConversionConfig::default().american_step(dec!(0.5));
+        assert_eq!(
+            Odds::decimal(dec!(2.375))
+                .unwrap()
+                .to_precise_american_custom(&config),
+            Ok(dec!(137.5))
+        );
+        assert_eq!(
+            Odds::fractional(11, 8)
+                .unwrap()
+                .to_precise_american_custom(&config),
+            Ok(dec!(137.5))
+        );
+        assert_eq!(
+            Odds::american(-110).unwrap().to_precise_american(),
+            Ok(dec!(-110))
+        );
+    }
+
+    #[test]
+    fn test_exact_comparison() {
+        let american = Odds::american(-150).unwrap();
+        let fractional = Odds::fractional(4, 6).unwrap();
+        let decimal = Odds::decimal(dec!(1.6667)).unwrap();
 
+        assert_eq!(american, fractional);
+        assert_eq!(Odds::fractional(2, 3).unwrap(), fractional);
         assert_eq!(
-            invalid_american.to_decimal(),
-            Err(ConversionError::AmericanZero)
+            Odds::decimal(dec!(2.50)).unwrap(),
+            Odds::fractional(3, 2).unwrap()
+        );
+        assert_ne!(decimal, fractional);
+
+        // Ordered by implied probability, longer price is less
+        assert!(decimal < fractional);
+        assert!(Odds::american(100).unwrap() < american);
+        assert!(Odds::decimal(dec!(10)).unwrap() < Odds::fractional(8, 1).unwrap());
+
+        let mut prices = [
+            Odds::decimal(dec!(3.5)).unwrap(),
+            Odds::american(-200).unwrap(),
+            Odds::fractional(1, 1).unwrap(),
+            Odds::american(150).unwrap(),
+        ];
+        prices.sort();
+        let sorted: Vec<_> = prices.iter().map(Odds::to_string).collect();
+        assert_eq!(sorted, ["3.5", "+150", "1/1", "-200"]);
+
+        // Rationals too big for cross multiplication in u128
+        let long = Odds::decimal(dec!(1.0000001234567890123456789)).unwrap();
+        let longer = Odds::decimal(dec!(1.0000001234567890123456790)).unwrap();
+        assert!(longer < long);
+        assert_eq!(long.cmp(&long), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_classification() {
+        let evens = [
+            Odds::fractional(1, 1).unwrap(),
+            Odds::fractional(5, 5).unwrap(),
+            Odds::decimal(dec!(2.000)).unwrap(),
+            Odds::american(100).unwrap(),
+            Odds::american(-100).unwrap(),
+            Odds::precise_american(dec!(100.0)).unwrap(),
+        ];
+        for odds in evens {
+            assert!(odds.is_evens(), "{odds}");
+            assert!(!odds.is_odds_on() && !odds.is_odds_against(), "{odds}");
+        }
+
+        let odds_on = [
+            Odds::fractional(4, 6).unwrap(),
+            Odds::fractional(99, 100).unwrap(),
+            Odds::decimal(dec!(1.9999)).unwrap(),
+            Odds::american(-101).unwrap(),
+            Odds::precise_american(dec!(-100.5)).unwrap(),
+        ];
+        for odds in odds_on {
+            assert!(odds.is_odds_on(), "{odds}");
+            assert!(!odds.is_evens() && !odds.is_odds_against(), "{odds}");
+        }
+
+        let odds_against = [
+            Odds::fractional(21, 20).unwrap(),
+            Odds::decimal(dec!(2.0001)).unwrap(),
+            Odds::american(101).unwrap(),
+            Odds::precise_american(dec!(100.5)).unwrap(),
+        ];
+        for odds in odds_against {
+            assert!(odds.is_odds_against(), "{odds}");
+            assert!(!odds.is_evens() && !odds.is_odds_on(), "{odds}");
+        }
+    }
+
+    #[test]
+    fn test_hash() {
+        use std::collections::HashSet;
+
+        let prices: HashSet<Odds> = [
+            Odds::american(-150).unwrap(),
+            Odds::fractional(4, 6).unwrap(),
+            Odds::fractional(2, 3).unwrap(),
+            Odds::decimal(dec!(2.50)).unwrap(),
+            Odds::decimal(dec!(2.5)).unwrap(),
+            Odds::american(150).unwrap(),
+            Odds::decimal(dec!(1.6667)).unwrap(),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(prices.len(), 3);
+    }
+
+    #[test]
+    fn test_tolerant_comparison() {
+        let fractional = Odds::fractional(4, 6).unwrap();
+        let decimal = Odds::decimal(dec!(1.67)).unwrap();
+
+        assert!(decimal.approx_eq(&fractional, dec!(0.002)));
+        assert!(!decimal.approx_eq(&fractional, dec!(0.0001)));
+        assert_eq!(
+            decimal.approx_cmp(&fractional, dec!(0.0001)),
+            Ordering::Less
+        );
+        assert_eq!(
+            fractional.approx_cmp(&Odds::american(-150).unwrap(), Decimal::ZERO),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Odds::american(150).unwrap().to_string(), "+150");
+        assert_eq!(Odds::american(-110).unwrap().to_string(), "-110");
+        assert_eq!(Odds::decimal(dec!(3.50)).unwrap().to_string(), "3.50");
+        assert_eq!(Odds::fractional(5, 2).unwrap().to_string(), "5/2");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("+150".parse::<Odds>().unwrap().to_american(), Ok(150));
+        assert_eq!("-110".parse::<Odds>().unwrap().to_american(), Ok(-110));
+        assert_eq!(
+            "+137.5".parse::<Odds>().unwrap().to_precise_american(),
+            Ok(dec!(137.5))
+        );
+        assert_eq!(
+            "3.50".parse::<Odds>().unwrap().to_string(),
+            "3.50".to_string()
+        );
+        assert_eq!(
+            " 5 / 2 ".parse::<Odds>().unwrap().to_fractional(),
+            Ok(frac(5, 2))
+        );
+
+        // Unsigned integer is decimal, not american
+        assert_decimal_ok_eq("3".parse::<Odds>().unwrap().to_decimal(), dec!(3));
+
+        assert_eq!(
+            "abc".parse::<Odds>().err(),
+            Some(ParseOddsError::InvalidFormat)
+        );
+        assert_eq!(
+            "5/x".parse::<Odds>().err(),
+            Some(ParseOddsError::InvalidFormat)
+        );
+        assert_eq!(
+            "5/0".parse::<Odds>().err(),
+            Some(ParseOddsError::InvalidOdds(
+                ConversionError::DenominatorZero { num: 5 }
+            ))
+        );
+        assert_eq!(
+            "+0".parse::<Odds>().err(),
+            Some(ParseOddsError::InvalidOdds(ConversionError::AmericanZero))
+        );
+        assert_eq!(
+            "0/1".parse::<Odds>().err(),
+            Some(ParseOddsError::InvalidOdds(
+                ConversionError::InvalidDecimal(dec!(1))
+            ))
+        );
+        assert_eq!(
+            "1.0".parse::<Odds>().err(),
+            Some(ParseOddsError::InvalidOdds(
+                ConversionError::InvalidDecimal(dec!(1.0))
+            ))
         );
     }
 
     #[test]
     fn test_to_fractional_str() {
         // From American
-        assert_eq!(Odds::American(-200).to_fractional_str().unwrap(), "1/2");
-        assert_eq!(Odds::American(250).to_fractional_str().unwrap(), "5/2");
+        assert_eq!(
+            Odds::american(-200).unwrap().to_fractional_str().unwrap(),
+            "1/2"
+        );
+        assert_eq!(
+            Odds::american(250).unwrap().to_fractional_str().unwrap(),
+            "5/2"
+        );
 
-        assert_eq!(Odds::American(-150).to_fractional_str().unwrap(), "4/6");
+        assert_eq!(
+            Odds::american(-150).unwrap().to_fractional_str().unwrap(),
+            "4/6"
+        );
 
         assert_eq!(
-            Odds::American(-150)
+            Odds::american(-150)
+                .unwrap()
                 .to_fractional_str_custom(&ConversionConfig::default().no_lookup())
                 .unwrap(),
             "2/3"
         );
 
         // From Decimal
-        assert_eq!(Odds::Decimal(dec!(1.5)).to_fractional_str().unwrap(), "1/2");
-        assert_eq!(Odds::Decimal(dec!(3.5)).to_fractional_str().unwrap(), "5/2");
         assert_eq!(
-            Odds::Decimal(dec!(2.25)).to_fractional_str().unwrap(),
-            "5/4"
+            Odds::decimal(dec!(1.5))
+                .unwrap()
+                .to_fractional_str()
+                .unwrap(),
+            "1/2"
         );
-
-        // From Fractional (passthrough)
         assert_eq!(
-            Odds::Fractional { num: 7, den: 2 }
+            Odds::decimal(dec!(3.5))
+                .unwrap()
                 .to_fractional_str()
                 .unwrap(),
-            "7/2"
+            "5/2"
         );
         assert_eq!(
-            Odds::Fractional { num: 1, den: 1 }
+            Odds::decimal(dec!(2.25))
+                .unwrap()
                 .to_fractional_str()
                 .unwrap(),
-            "1/1"
+            "5/4"
         );
 
-        // Error cases
-        assert!(Odds::American(0).to_fractional_str().is_err());
-        assert!(Odds::Decimal(dec!(0.9)).to_fractional_str().is_err());
-        assert!(
-            Odds::Fractional { num: 1, den: 0 }
-                .to_fractional_str()
-                .is_err()
+        // From Fractional (passthrough)
+        assert_eq!(
+            Odds::fractional(7, 2).unwrap().to_fractional_str().unwrap(),
+            "7/2"
+        );
+        assert_eq!(
+            Odds::fractional(1, 1).unwrap().to_fractional_str().unwrap(),
+            "1/1"
         );
     }
 
     #[test]
     fn test_to_decimal_str() {
         // From American
-        assert_eq!(Odds::American(-500).to_decimal_str().unwrap(), "1.20");
-        assert_eq!(Odds::American(200).to_decimal_str().unwrap(), "3.00");
-        assert_eq!(Odds::American(-110).to_decimal_str().unwrap(), "1.91"); // Tests rounding
+        assert_eq!(
+            Odds::american(-500).unwrap().to_decimal_str().unwrap(),
+            "1.20"
+        );
+        assert_eq!(
+            Odds::american(200).unwrap().to_decimal_str().unwrap(),
+            "3.00"
+        );
+        assert_eq!(
+            Odds::american(-110).unwrap().to_decimal_str().unwrap(),
+            "1.91"
+        ); // Tests rounding
 
         // From Decimal
-        assert_eq!(Odds::Decimal(dec!(4.0)).to_decimal_str().unwrap(), "4.00");
-        assert_eq!(Odds::Decimal(dec!(2.75)).to_decimal_str().unwrap(), "2.75");
         assert_eq!(
-            Odds::Decimal(dec!(1.3333)).to_decimal_str().unwrap(),
+            Odds::decimal(dec!(4.0)).unwrap().to_decimal_str().unwrap(),
+            "4.00"
+        );
+        assert_eq!(
+            Odds::decimal(dec!(2.75)).unwrap().to_decimal_str().unwrap(),
+            "2.75"
+        );
+        assert_eq!(
+            Odds::decimal(dec!(1.3333))
+                .unwrap()
+                .to_decimal_str()
+                .unwrap(),
             "1.33"
         ); // Tests rounding
 
         // From Fractional
         assert_eq!(
-            Odds::Fractional { num: 1, den: 2 }
-                .to_decimal_str()
-                .unwrap(),
+            Odds::fractional(1, 2).unwrap().to_decimal_str().unwrap(),
             "1.50"
         );
         assert_eq!(
-            Odds::Fractional { num: 4, den: 1 }
-                .to_decimal_str()
-                .unwrap(),
+            Odds::fractional(4, 1).unwrap().to_decimal_str().unwrap(),
             "5.00"
         );
         assert_eq!(
-            Odds::Fractional { num: 2, den: 3 }
-                .to_decimal_str()
-                .unwrap(),
+            Odds::fractional(2, 3).unwrap().to_decimal_str().unwrap(),
             "1.67"
         ); // Tests rounding
-
-        // Error cases
-        assert!(Odds::American(0).to_decimal_str().is_err());
-        assert!(Odds::Decimal(dec!(-2.0)).to_decimal_str().is_err());
-        assert!(
-            Odds::Fractional { num: 1, den: 0 }
-                .to_decimal_str()
-                .is_err()
-        );
     }
 }
@@ -0,0 +1,244 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc, LazyLock, PoisonError, RwLock,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use crate::{ConversionConfig, LookupTable};
+
+/// Named conversion configs and lookup tables which can be replaced at runtime, f. ex. hot-reloaded brand tables.
+///
+/// Readers get an `Arc` snapshot, so replacing an entry doesn't affect conversions already using the previous one.
+/// Configs hold their own lookup tables, so a config built from a replaced table has to be registered again.
+#[derive(Debug, Default)]
+pub struct ConfigRegistry {
+    configs: RwLock<HashMap<String, Arc<ConversionConfig>>>,
+    tables: RwLock<HashMap<String, Arc<LookupTable>>>,
+    default: RwLock<Option<Arc<ConversionConfig>>>,
+    /// Set together with `default`, so the default functions don't take the lock until a default is set.
+    has_default: AtomicBool,
+}
+
+static GLOBAL_REGISTRY: LazyLock<ConfigRegistry> = LazyLock::new(ConfigRegistry::new);
+
+impl ConfigRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry shared by the whole process. Its default config is used by the default functions
+    /// (f. ex. [`american_to_decimal`](crate::american_to_decimal)) when set. Until then they use
+    /// the built-in default without locking.
+    pub fn global() -> &'static ConfigRegistry {
+        &GLOBAL_REGISTRY
+    }
+
+    /// Register config under given name, returning the replaced one.
+    pub fn register_config(
+        &self,
+        name: impl Into<String>,
+        config: impl Into<Arc<ConversionConfig>>,
+    ) -> Option<Arc<ConversionConfig>> {
+        write(&self.configs).insert(name.into(), config.into())
+    }
+
+    pub fn config(&self, name: &str) -> Option<Arc<ConversionConfig>> {
+        read(&self.configs).get(name).cloned()
+    }
+
+    pub fn remove_config(&self, name: &str) -> Option<Arc<ConversionConfig>> {
+        write(&self.configs).remove(name)
+    }
+
+    /// Register lookup table under given name, returning the replaced one.
+    pub fn register_table(
+        &self,
+        name: impl Into<String>,
+        table: impl Into<Arc<LookupTable>>,
+    ) -> Option<Arc<LookupTable>> {
+        write(&self.tables).insert(name.into(), table.into())
+    }
+
+    pub fn table(&self, name: &str) -> Option<Arc<LookupTable>> {
+        read(&self.tables).get(name).cloned()
+    }
+
+    pub fn remove_table(&self, name: &str) -> Option<Arc<LookupTable>> {
+        write(&self.tables).remove(name)
+    }
+
+    /// Replace the default config, returning the previous one if it was set.
+    pub fn set_default(
+        &self,
+        config: impl Into<Arc<ConversionConfig>>,
+    ) -> Option<Arc<ConversionConfig>> {
+        let mut default = write(&self.default);
+        self.has_default.store(true, Ordering::Release);
+        default.replace(config.into())
+    }
+
+    /// Go back to [`ConversionConfig::default`], returning the previous default if it was set.
+    pub fn reset_default(&self) -> Option<Arc<ConversionConfig>> {
+        let mut default = write(&self.default);
+        self.has_default.store(false, Ordering::Release);
+        default.take()
+    }
+
+    /// Default config if set, [`ConversionConfig::default`] otherwise.
+    pub fn default_config(&self) -> Arc<ConversionConfig> {
+        read(&self.default)
+            .clone()
+            .unwrap_or_else(|| Arc::new(ConversionConfig::default()))
+    }
+
+    /// Run `f` with the default config, without cloning the built-in one.
+    pub(crate) fn with_default<R>(&self, f: impl FnOnce(&ConversionConfig) -> R) -> R {
+        if !self.has_default.load(Ordering::Acquire) {
+            return f(ConversionConfig::builtin_default());
+        }
+        // Snapshot taken, so the lock isn't held during conversion
+        let config = read(&self.default).clone();
+        match config {
+            Some(config) => f(&config),
+            None => f(ConversionConfig::builtin_default()),
+        }
+    }
+}
+
+/// Run `f` with the config of the default functions: the global default if set, [`ConversionConfig::default`] otherwise.
+pub(crate) fn with_default_config<R>(f: impl FnOnce(&ConversionConfig) -> R) -> R {
+    ConfigRegistry::global().with_default(f)
+}
+
+// Entries are replaced as a whole, so values behind a poisoned lock are still consistent
+fn read<T>(lock: &RwLock<T>) -> std::sync::RwLockReadGuard<'_, T> {
+    lock.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn write<T>(lock: &RwLock<T>) -> std::sync::RwLockWriteGuard<'_, T> {
+    lock.write().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use rust_decimal_macros::dec;
+
+    use crate::{
+        LookupVariant, PriceRounding, decimal_to_fractional_custom, testing_helpers::frac,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_named_entries() {
+        let registry = ConfigRegistry::new();
+        assert!(registry.config("uk").is_none());
+
+        let uk = ConversionConfig::uk_traditional();
+        assert!(registry.register_config("uk", uk.clone()).is_none());
+        let config = registry.config("uk").unwrap();
+        assert_eq!(config.lookup_tables_variant, LookupVariant::Extended);
+
+        // Replaced atomically, readers keep their snapshot
+        let previous = registry
+            .register_config("uk", ConversionConfig::us_sportsbook())
+            .unwrap();
+        assert!(Arc::ptr_eq(&previous, &config));
+        assert_eq!(config.lookup_tables_variant, LookupVariant::Extended);
+        assert_eq!(
+            registry.config("uk").unwrap().price_rounding,
+            PriceRounding::Shorten
+        );
+        assert!(registry.remove_config("uk").is_some());
+        assert!(registry.config("uk").is_none());
+
+        let table = LookupTable::from_csv("1.91,-110,10/11").unwrap();
+        registry.register_table("brand", table);
+        let table = registry.table("brand").unwrap();
+        let config = ConversionConfig::default().custom_lookup(table);
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(1.91), &config).unwrap(),
+            (10, 11)
+        );
+
+        let reloaded = LookupTable::from_csv("1.91,-110,9/10").unwrap();
+        let previous = registry.register_table("brand", reloaded).unwrap();
+        assert_eq!(previous.len(), 1);
+        let config = ConversionConfig::default().custom_lookup(registry.table("brand").unwrap());
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(1.91), &config).unwrap(),
+            (9, 10)
+        );
+        assert!(registry.remove_table("brand").is_some());
+        assert!(registry.table("brand").is_none());
+    }
+
+    #[test]
+    fn test_default() {
+        let registry = ConfigRegistry::new();
+        assert!(!registry.has_default.load(Ordering::Relaxed));
+        assert_eq!(
+            registry.with_default(|config| config.lookup_tables_variant.clone()),
+            LookupVariant::Basic
+        );
+        assert_eq!(
+            registry.default_config().lookup_tables_variant,
+            LookupVariant::Basic
+        );
+
+        assert!(
+            registry
+                .set_default(ConversionConfig::default().no_lookup())
+                .is_none()
+        );
+        assert!(registry.has_default.load(Ordering::Relaxed));
+        assert_eq!(
+            registry.with_default(|config| decimal_to_fractional_custom(dec!(1.67), config)),
+            Ok(frac(67, 100))
+        );
+        assert_eq!(
+            registry.default_config().lookup_tables_variant,
+            LookupVariant::None
+        );
+
+        let previous = registry.reset_default().unwrap();
+        assert_eq!(previous.lookup_tables_variant, LookupVariant::None);
+        assert!(registry.reset_default().is_none());
+        assert!(!registry.has_default.load(Ordering::Relaxed));
+        assert_eq!(
+            registry.default_config().lookup_tables_variant,
+            LookupVariant::Basic
+        );
+    }
+
+    #[test]
+    fn test_concurrent_replacement() {
+        let registry = ConfigRegistry::new();
+        registry.register_config("brand", ConversionConfig::default());
+
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                for step in 1..=100 {
+                    registry.register_config(
+                        "brand",
+                        ConversionConfig::default().american_step(step.into()),
+                    );
+                }
+            });
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for _ in 0..100 {
+                        let config = registry.config("brand").unwrap();
+                        assert!(config.american_step >= dec!(1));
+                    }
+                });
+            }
+        });
+
+        assert_eq!(registry.config("brand").unwrap().american_step, dec!(100));
+    }
+}
@@ -0,0 +1,309 @@
+use rust_decimal::{Decimal, MathematicalOps};
+use rust_decimal_macros::dec;
+
+use crate::{CalculationError, Odds};
+
+/// Forecast which can be scored - either a probability or a price implying it.
+pub trait Forecast {
+    fn probability(&self) -> Result<Decimal, CalculationError>;
+}
+
+impl Forecast for Decimal {
+    fn probability(&self) -> Result<Decimal, CalculationError> {
+        if *self < Decimal::ZERO || *self > Decimal::ONE {
+            return Err(CalculationError::InvalidProbability);
+        }
+        Ok(*self)
+    }
+}
+
+impl Forecast for Odds {
+    fn probability(&self) -> Result<Decimal, CalculationError> {
+        Ok(self.implied_probability())
+    }
+}
+
+// Probabilities are clipped to avoid infinite log loss for certain forecasts which turned out wrong.
+const LOG_LOSS_EPSILON: Decimal = dec!(0.000000000000001);
+
+fn outcome_value(outcome: bool) -> Decimal {
+    if outcome { Decimal::ONE } else { Decimal::ZERO }
+}
+
+/// Mean squared difference between forecast probabilities and outcomes (lower is better).
+pub fn brier_score<F: Forecast>(predictions: &[(F, bool)]) -> Result<Decimal, CalculationError> {
+    if predictions.is_empty() {
+        return Err(CalculationError::EmptyInput);
+    }
+
+    let mut sum = Decimal::ZERO;
+    for (forecast, outcome) in predictions {
+        let diff = forecast.probability()? - outcome_value(*outcome);
+        sum += diff * diff;
+    }
+
+    Ok(sum / Decimal::from(predictions.len()))
+}
+
+/// Mean negative log-likelihood of the outcomes (lower is better).
+///
+/// Probabilities are clipped to `[1e-15, 1 - 1e-15]`.
+pub fn log_loss<F: Forecast>(predictions: &[(F, bool)]) -> Result<Decimal, CalculationError> {
+    if predictions.is_empty() {
+        return Err(CalculationError::EmptyInput);
+    }
+
+    let mut sum = Decimal::ZERO;
+    for (forecast, outcome) in predictions {
+        let p = forecast
+            .probability()?
+            .clamp(LOG_LOSS_EPSILON, Decimal::ONE - LOG_LOSS_EPSILON);
+        sum -= if *outcome {
+            p.ln()
+        } else {
+            (Decimal::ONE - p).ln()
+        };
+    }
+
+    Ok(sum / Decimal::from(predictions.len()))
+}
+
+/// Ranked probability score for events with ordered outcomes (lower is better).
+///
+/// Each event is a list of forecasts for all outcomes in order and the index of the outcome which happened.
+/// Probabilities of each event are normalized to sum up to 1, so prices with bookmaker's margin can be used directly.
+/// Events with fewer than 2 outcomes have nothing to rank, so they are left out of the average.
+pub fn ranked_probability_score<F: Forecast>(
+    events: &[(Vec<F>, usize)],
+) -> Result<Decimal, CalculationError> {
+    if events.is_empty() {
+        return Err(CalculationError::EmptyInput);
+    }
+
+    let mut total = Decimal::ZERO;
+    let mut scored = 0usize;
+    for (forecasts, outcome) in events {
+        if *outcome >= forecasts.len() {
+            return Err(CalculationError::InvalidSelection);
+        }
+        if forecasts.len() < 2 {
+            continue;
+        }
+
+        let probabilities = forecasts
+            .iter()
+            .map(Forecast::probability)
+            .collect::<Result<Vec<_>, _>>()?;
+        let sum: Decimal = probabilities.iter().sum();
+        if sum.is_zero() {
+            return Err(CalculationError::InvalidProbability);
+        }
+
+        let mut cumulative = Decimal::ZERO;
+        let mut score = Decimal::ZERO;
+        for (i, p) in probabilities
+            .iter()
+            .enumerate()
+            .take(probabilities.len() - 1)
+        {
+            cumulative += p / sum;
+            let observed = outcome_value(i >= *outcome);
+            score += (cumulative - observed) * (cumulative - observed);
+        }
+
+        total += score / Decimal::from(probabilities.len() - 1);
+        scored += 1;
+    }
+
+    if scored == 0 {
+        return Err(CalculationError::EmptyInput);
+    }
+    Ok(total / Decimal::from(scored))
+}
+
+/// Forecasts falling into a single probability band.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalibrationBucket {
+    /// Lower bound of the band (inclusive).
+    pub lower: Decimal,
+    /// Upper bound of the band (exclusive, except for the last band).
+    pub upper: Decimal,
+    pub count: usize,
+    /// Mean forecast probability in the band, `None` if the band is empty.
+    pub mean_predicted: Option<Decimal>,
+    /// Fraction of forecasts in the band which happened, `None` if the band is empty.
+    pub observed_frequency: Option<Decimal>,
+}
+
+/// Forecasts grouped into equal probability bands, used to check if f. ex. 20% forecasts happen 20% of the time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalibrationReport {
+    pub buckets: Vec<CalibrationBucket>,
+}
+
+/// Group forecasts into `bands` equal probability bands.
+pub fn calibration_report<F: Forecast>(
+    predictions: &[(F, bool)],
+    bands: usize,
+) -> Result<CalibrationReport, CalculationError> {
+    if bands == 0 {
+        return Err(CalculationError::InvalidBands);
+    }
+
+    let width = Decimal::ONE / Decimal::from(bands);
+    let mut sums = vec![(0usize, Decimal::ZERO, 0usize); bands];
+
+    for (forecast, outcome) in predictions {
+        let p = forecast.probability()?;
+        let index = (p / width)
+            .floor()
+            .try_into()
+            .unwrap_or(usize::MAX)
+            .min(bands - 1);
+
+        let (count, predicted, happened) = &mut sums[index];
+        *count += 1;
+        *predicted += p;
+        *happened += usize::from(*outcome);
+    }
+
+    let buckets = sums
+        .into_iter()
+        .enumerate()
+        .map(|(i, (count, predicted, happened))| {
+            let n = Decimal::from(count);
+            CalibrationBucket {
+                lower: width * Decimal::from(i),
+                upper: if i == bands - 1 {
+                    Decimal::ONE
+                } else {
+                    width * Decimal::from(i + 1)
+                },
+                count,
+                mean_predicted: (count > 0).then(|| predicted / n),
+                observed_frequency: (count > 0).then(|| Decimal::from(happened) / n),
+            }
+        })
+        .collect();
+
+    Ok(CalibrationReport { buckets })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing_helpers::assert_decimal_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_brier_score() {
+        let predictions = [(dec!(0.8), true), (dec!(0.3), false), (dec!(0.6), false)];
+        // (0.04 + 0.09 + 0.36) / 3
+        assert_decimal_eq(brier_score(&predictions).unwrap(), dec!(0.1633));
+
+        // Prices are scored by their implied probabilities
+        let predictions = [
+            (Odds::american(-400).unwrap(), true),
+            (Odds::fractional(3, 1).unwrap(), false),
+        ];
+        // (0.04 + 0.0625) / 2
+        assert_decimal_eq(brier_score(&predictions).unwrap(), dec!(0.05125));
+
+        assert_eq!(
+            brier_score::<Decimal>(&[]),
+            Err(CalculationError::EmptyInput)
+        );
+        assert_eq!(
+            brier_score(&[(dec!(1.5), true)]),
+            Err(CalculationError::InvalidProbability)
+        );
+    }
+
+    #[test]
+    fn test_log_loss() {
+        let predictions = [(dec!(0.8), true), (dec!(0.4), false)];
+        // -(ln 0.8 + ln 0.6) / 2
+        assert_decimal_eq(log_loss(&predictions).unwrap(), dec!(0.3670));
+
+        let predictions = [(Odds::decimal(dec!(2.0)).unwrap(), true)];
+        assert_decimal_eq(log_loss(&predictions).unwrap(), dec!(0.6931));
+
+        // Certain forecast which turned out wrong is clipped instead of infinite
+        assert_decimal_eq(log_loss(&[(Decimal::ZERO, true)]).unwrap(), dec!(34.5388));
+    }
+
+    #[test]
+    fn test_ranked_probability_score() {
+        // Home / draw / away, home won
+        let events = vec![(vec![dec!(0.5), dec!(0.3), dec!(0.2)], 0)];
+        // ((0.5 - 1)^2 + (0.8 - 1)^2) / 2
+        assert_decimal_eq(ranked_probability_score(&events).unwrap(), dec!(0.145));
+
+        // Prices with margin are normalized first
+        let events = vec![(
+            vec![
+                Odds::decimal(dec!(1.9)).unwrap(),
+                Odds::decimal(dec!(3.8)).unwrap(),
+                Odds::decimal(dec!(5.7)).unwrap(),
+            ],
+            2,
+        )];
+        // Fair probabilities 6/11, 3/11, 2/11
+        // ((0.5455)^2 + (0.8182)^2) / 2
+        assert_decimal_eq(ranked_probability_score(&events).unwrap(), dec!(0.4835));
+
+        assert_eq!(
+            ranked_probability_score(&[(vec![dec!(0.5), dec!(0.5)], 2)]),
+            Err(CalculationError::InvalidSelection)
+        );
+
+        // Single outcome events don't count towards the average
+        let events = vec![
+            (vec![dec!(0.5), dec!(0.3), dec!(0.2)], 0),
+            (vec![dec!(1)], 0),
+        ];
+        assert_decimal_eq(ranked_probability_score(&events).unwrap(), dec!(0.145));
+        assert_eq!(
+            ranked_probability_score(&[(vec![dec!(1)], 0)]),
+            Err(CalculationError::EmptyInput)
+        );
+    }
+
+    #[test]
+    fn test_calibration_report() {
+        let predictions = [
+            (dec!(0.1), false),
+            (dec!(0.2), true),
+            (dec!(0.6), true),
+            (dec!(0.7), false),
+            (dec!(1.0), true),
+        ];
+
+        let report = calibration_report(&predictions, 4).unwrap();
+        assert_eq!(report.buckets.len(), 4);
+
+        let first = &report.buckets[0];
+        assert_eq!((first.lower, first.upper), (dec!(0), dec!(0.25)));
+        assert_eq!(first.count, 2);
+        assert_decimal_eq(first.mean_predicted.unwrap(), dec!(0.15));
+        assert_decimal_eq(first.observed_frequency.unwrap(), dec!(0.5));
+
+        assert_eq!(report.buckets[1].count, 0);
+        assert_eq!(report.buckets[1].mean_predicted, None);
+
+        assert_eq!(report.buckets[2].count, 2);
+
+        // Probability of 1 falls into the last band
+        assert_eq!(report.buckets[3].count, 1);
+        assert_eq!(report.buckets[3].upper, Decimal::ONE);
+        assert_decimal_eq(report.buckets[3].observed_frequency.unwrap(), dec!(1));
+
+        assert_eq!(
+            calibration_report(&predictions, 0),
+            Err(CalculationError::InvalidBands)
+        );
+        // No predictions give empty bands
+        let report = calibration_report::<Decimal>(&[], 2).unwrap();
+        assert!(report.buckets.iter().all(|bucket| bucket.count == 0));
+    }
+}
@@ -0,0 +1,362 @@
+use std::borrow::Cow;
+
+use rust_decimal::{Decimal, RoundingStrategy, prelude::ToPrimitive};
+use rust_decimal_macros::dec;
+
+use crate::{ConversionError, Fraction, LookupEntry, LookupTable};
+
+/// Rules lookup tables are generated from, f. ex. to extend the built-in ladder with more prices.
+///
+/// Every fraction gives one price: its decimal odds rounded to `decimal_places` (unless overridden)
+/// and its american odds rounded to `american_step`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableRules {
+    /// Traditional fractions, earlier ones win when rounded to the same decimal or american price.
+    pub fractions: Cow<'static, [(u32, u32)]>,
+    /// Decimal places decimal prices are rounded to.
+    pub decimal_places: u32,
+    pub decimal_rounding: RoundingStrategy,
+    /// Step american odds are rounded to (whole numbers at least), `None` for tables without american odds.
+    pub american_step: Option<Decimal>,
+    pub american_rounding: RoundingStrategy,
+    /// Decimal prices used instead of the rounded ones, f. ex. 2.62 for 13/8.
+    pub decimal_overrides: Cow<'static, [((u32, u32), Decimal)]>,
+}
+
+/// Lookup tables generated from [`TableRules`], sorted by key.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GeneratedTables {
+    pub decimal_to_fraction: Vec<(Decimal, (u32, u32))>,
+    pub american_to_fraction: Vec<(i32, (u32, u32))>,
+    pub american_to_decimal: Vec<(i32, Decimal)>,
+}
+
+impl TableRules {
+    /// Rules with prices rounded half up to 2 decimal places and whole american odds.
+    pub fn new(fractions: impl Into<Cow<'static, [(u32, u32)]>>) -> Self {
+        Self {
+            fractions: fractions.into(),
+            decimal_places: 2,
+            decimal_rounding: RoundingStrategy::MidpointAwayFromZero,
+            american_step: Some(Decimal::ONE),
+            american_rounding: RoundingStrategy::MidpointAwayFromZero,
+            decimal_overrides: Cow::Borrowed(&[]),
+        }
+    }
+
+    /// Rules of the basic built-in tables.
+    pub fn basic() -> Self {
+        Self::new(BASIC_FRACTIONS).decimal_overrides(BASIC_DECIMAL_OVERRIDES)
+    }
+
+    /// Rules of the extended built-in tables, which have no american odds
+    /// and are used together with the basic ones.
+    pub fn extended() -> Self {
+        Self::new(EXTENDED_FRACTIONS)
+            .no_american()
+            .decimal_overrides(EXTENDED_DECIMAL_OVERRIDES)
+    }
+
+    pub fn fractions(mut self, fractions: impl Into<Cow<'static, [(u32, u32)]>>) -> Self {
+        self.fractions = fractions.into();
+        self
+    }
+
+    pub fn decimal_places(mut self, decimal_places: u32, rounding: RoundingStrategy) -> Self {
+        self.decimal_places = decimal_places;
+        self.decimal_rounding = rounding;
+        self
+    }
+
+    pub fn american_step(mut self, step: Decimal, rounding: RoundingStrategy) -> Self {
+        self.american_step = Some(step);
+        self.american_rounding = rounding;
+        self
+    }
+
+    pub fn no_american(mut self) -> Self {
+        self.american_step = None;
+        self
+    }
+
+    pub fn decimal_overrides(
+        mut self,
+        overrides: impl Into<Cow<'static, [((u32, u32), Decimal)]>>,
+    ) -> Self {
+        self.decimal_overrides = overrides.into();
+        self
+    }
+
+    /// Generate tables, failing on fractions with zero denominator or prices not longer than 1.0.
+    pub fn generate(&self) -> Result<GeneratedTables, ConversionError> {
+        let mut tables = GeneratedTables::default();
+
+        for &(num, den) in self.fractions.iter() {
+            let fraction = Fraction::new(num, den)?;
+            if num == 0 {
+                return Err(ConversionError::InvalidDecimal(Decimal::ONE));
+            }
+
+            let decimal = match self
+                .decimal_overrides
+                .iter()
+                .find(|(overridden, _)| *overridden == (num, den))
+            {
+                Some((_, decimal)) => *decimal,
+                None => (fraction.to_decimal() + Decimal::ONE)
+                    .round_dp_with_strategy(self.decimal_places, self.decimal_rounding),
+            }
+            .normalize();
+            if decimal <= Decimal::ONE {
+                return Err(ConversionError::InvalidDecimal(decimal));
+            }
+            tables.decimal_to_fraction.push((decimal, (num, den)));
+
+            if let Some(step) = self.american_step {
+                let american = self.american(num, den, step)?;
+                tables.american_to_fraction.push((american, (num, den)));
+                tables.american_to_decimal.push((american, decimal));
+            }
+        }
+
+        // Stable sort, so the first of equal prices is kept
+        tables
+            .decimal_to_fraction
+            .sort_by_key(|(decimal, _)| *decimal);
+        tables
+            .decimal_to_fraction
+            .dedup_by_key(|(decimal, _)| *decimal);
+        tables
+            .american_to_fraction
+            .sort_by_key(|(american, _)| *american);
+        tables
+            .american_to_fraction
+            .dedup_by_key(|(american, _)| *american);
+        tables
+            .american_to_decimal
+            .sort_by_key(|(american, _)| *american);
+        tables
+            .american_to_decimal
+            .dedup_by_key(|(american, _)| *american);
+
+        Ok(tables)
+    }
+
+    /// American odds of the exact fraction, rounded to the step.
+    fn american(&self, num: u32, den: u32, step: Decimal) -> Result<i32, ConversionError> {
+        let (num, den) = (Decimal::from(num), Decimal::from(den));
+        let american = if num >= den {
+            Decimal::ONE_HUNDRED * num / den
+        } else {
+            -Decimal::ONE_HUNDRED * den / num
+        };
+        let rounded = if step > Decimal::ZERO {
+            (american / step).round_dp_with_strategy(0, self.american_rounding) * step
+        } else {
+            american
+        };
+        rounded
+            .round_dp_with_strategy(0, self.american_rounding)
+            .to_i32()
+            .ok_or(ConversionError::DecimalOverflow(american))
+    }
+}
+
+impl GeneratedTables {
+    /// Custom lookup table with the prices which have american odds.
+    pub fn to_lookup_table(&self) -> Result<LookupTable, ConversionError> {
+        LookupTable::from_entries(self.american_to_decimal.iter().filter_map(
+            |(american, decimal)| {
+                let index = self
+                    .american_to_fraction
+                    .binary_search_by_key(american, |(american, _)| *american)
+                    .ok()?;
+                let (num, den) = self.american_to_fraction[index].1;
+                Some(LookupEntry {
+                    decimal: *decimal,
+                    american: *american,
+                    fraction: Fraction::new(num, den).ok()?,
+                })
+            },
+        ))
+    }
+}
+
+/// Fractions of the basic built-in tables, from the shortest.
+#[rustfmt::skip]
+const BASIC_FRACTIONS: &[(u32, u32)] = &[
+    (1, 100), (1, 5), (2, 9), (1, 4), (2, 7), (3, 10), (1, 3), (4, 11),
+    (2, 5), (4, 9), (1, 2), (8, 15), (4, 7), (8, 13), (4, 6), (8, 11),
+    (4, 5), (5, 6), (10, 11), (1, 1), (21, 20), (11, 10), (23, 20), (6, 5),
+    (5, 4), (11, 8), (7, 5), (6, 4), (8, 5), (13, 8), (7, 4), (9, 5),
+    (15, 8), (2, 1), (11, 5), (9, 4), (12, 5), (5, 2), (13, 5), (11, 4),
+    (3, 1), (16, 5), (10, 3), (7, 2), (4, 1), (9, 2), (5, 1), (11, 2),
+    (6, 1), (13, 2), (7, 1), (15, 2), (8, 1), (9, 1), (10, 1), (11, 1),
+    (12, 1), (13, 1), (14, 1), (15, 1), (16, 1), (18, 1), (20, 1), (25, 1),
+    (33, 1), (50, 1), (66, 1), (100, 1), (1000, 1),
+];
+
+const BASIC_DECIMAL_OVERRIDES: &[((u32, u32), Decimal)] = &[((13, 8), dec!(2.62))];
+
+/// Fractions of the extended built-in tables, from the shortest.
+#[rustfmt::skip]
+const EXTENDED_FRACTIONS: &[(u32, u32)] = &[
+    (1, 1000), (1, 750), (1, 500), (1, 400), (1, 300), (1, 250), (1, 200), (1, 150),
+    (1, 80), (1, 66), (1, 50), (1, 40), (1, 33), (1, 25), (1, 20), (1, 18),
+    (1, 16), (1, 14), (1, 12), (1, 11), (1, 10), (1, 9), (1, 8), (2, 15),
+    (1, 7), (2, 13), (1, 6), (2, 11), (19, 100), (21, 100), (23, 100), (6, 25),
+    (13, 50), (27, 100), (31, 100), (8, 25), (17, 50), (7, 20), (37, 100), (19, 50),
+    (39, 100), (41, 100), (21, 50), (43, 100), (9, 20), (23, 50), (40, 85), (12, 25),
+    (49, 100), (51, 100), (13, 25), (27, 50), (11, 20), (14, 25), (29, 50), (59, 100),
+    (3, 5), (8, 13), (63, 100), (16, 25), (13, 20), (4, 6), (34, 50), (69, 100),
+    (7, 10), (71, 100), (8, 11), (37, 50), (3, 4), (19, 25), (77, 100), (39, 50),
+    (79, 100), (81, 100), (41, 50), (21, 25), (17, 20), (20, 23), (87, 100), (22, 25),
+    (89, 100), (9, 10), (23, 25), (93, 100), (47, 50), (20, 21), (24, 25), (97, 100),
+    (49, 50), (99, 100), (101, 100), (51, 50), (103, 100), (26, 25), (53, 50), (107, 100),
+    (27, 25), (109, 100), (111, 100), (28, 25), (113, 100), (57, 50), (29, 25), (117, 100),
+    (59, 50), (119, 100), (121, 100), (61, 50), (123, 100), (31, 25), (63, 50), (127, 100),
+    (32, 25), (13, 10), (33, 25), (67, 50), (27, 20), (34, 25), (11, 8), (71, 50),
+    (36, 25), (29, 20), (73, 50), (37, 25), (38, 25), (77, 50), (39, 25), (79, 50),
+    (41, 25), (83, 50), (42, 25), (17, 10), (43, 25), (87, 50), (44, 25), (89, 50),
+    (91, 50), (46, 25), (93, 50), (15, 8), (19, 10), (48, 25), (97, 50), (49, 25),
+    (99, 50), (41, 20), (21, 10), (85, 40), (43, 20), (23, 10), (47, 20), (49, 20),
+    (51, 20), (53, 20), (27, 10), (14, 5), (57, 20), (59, 20), (61, 20), (31, 10),
+    (63, 20), (13, 4), (33, 10), (67, 20), (17, 5), (69, 20), (71, 20), (18, 5),
+    (73, 20), (37, 10), (15, 4), (19, 5), (77, 20), (39, 10), (79, 20), (41, 10),
+    (21, 5), (43, 10), (22, 5), (23, 5), (47, 10), (24, 5), (49, 10), (26, 5),
+    (27, 5), (28, 5), (29, 5), (31, 5), (32, 5), (33, 5), (34, 5), (36, 5),
+    (37, 5), (38, 5), (39, 5), (41, 5), (42, 5), (17, 2), (43, 5), (44, 5),
+    (22, 1), (28, 1), (30, 1), (35, 1), (40, 1), (45, 1), (55, 1), (60, 1),
+    (70, 1), (75, 1), (80, 1), (85, 1), (90, 1), (95, 1), (110, 1), (120, 1),
+    (125, 1), (130, 1), (140, 1), (150, 1), (175, 1), (200, 1), (225, 1), (250, 1),
+    (275, 1), (300, 1), (400, 1), (500, 1),
+];
+
+/// Prices of the extended tables which don't follow the rounding: very short prices, traditional
+/// decimals like 1.12 for 1/8 and alternative decimals of basic fractions like 1.66 for 4/6.
+#[rustfmt::skip]
+const EXTENDED_DECIMAL_OVERRIDES: &[((u32, u32), Decimal)] = &[
+    ((1, 1000), dec!(1.001)), ((1, 750), dec!(1.0013)), ((1, 500), dec!(1.002)),
+    ((1, 400), dec!(1.0025)), ((1, 300), dec!(1.003)), ((1, 250), dec!(1.004)),
+    ((1, 200), dec!(1.005)), ((1, 150), dec!(1.007)), ((1, 80), dec!(1.012)),
+    ((1, 66), dec!(1.015)), ((1, 40), dec!(1.025)), ((1, 18), dec!(1.055)),
+    ((1, 8), dec!(1.12)), ((1, 6), dec!(1.16)), ((8, 13), dec!(1.61)),
+    ((4, 6), dec!(1.66)), ((8, 11), dec!(1.72)), ((20, 23), dec!(1.86)),
+    ((11, 8), dec!(2.37)), ((15, 8), dec!(2.87)), ((85, 40), dec!(3.125)),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ConversionConfig, decimal_to_fractional_custom,
+        lookup_tables::{
+            StaticTable, TableKey, get_american_to_decimal_extended_map,
+            get_american_to_decimal_map, get_american_to_fraction_extended_map,
+            get_american_to_fraction_map, get_decimal_to_fraction_extended_map,
+            get_decimal_to_fraction_map,
+        },
+    };
+
+    use super::*;
+
+    fn entries<K: TableKey, V: Copy>(table: &StaticTable<K, V>) -> Vec<(K, V)> {
+        table.iter().map(|(key, value)| (key, *value)).collect()
+    }
+
+    #[test]
+    fn test_builtin_tables() {
+        let basic = TableRules::basic().generate().unwrap();
+        assert_eq!(
+            basic.decimal_to_fraction,
+            entries(get_decimal_to_fraction_map())
+        );
+        assert_eq!(
+            basic.american_to_fraction,
+            entries(get_american_to_fraction_map())
+        );
+        assert_eq!(
+            basic.american_to_decimal,
+            entries(get_american_to_decimal_map())
+        );
+
+        let extended = TableRules::extended().generate().unwrap();
+        assert_eq!(
+            extended.decimal_to_fraction,
+            entries(get_decimal_to_fraction_extended_map())
+        );
+        assert_eq!(
+            extended.american_to_fraction,
+            entries(get_american_to_fraction_extended_map())
+        );
+        assert_eq!(
+            extended.american_to_decimal,
+            entries(get_american_to_decimal_extended_map())
+        );
+    }
+
+    #[test]
+    fn test_extended_ladder() {
+        let mut fractions = TableRules::basic().fractions.into_owned();
+        fractions.extend([(200, 1), (500, 1)]);
+        let tables = TableRules::basic().fractions(fractions).generate().unwrap();
+        assert_eq!(tables.decimal_to_fraction.len(), 71);
+        assert_eq!(
+            tables.decimal_to_fraction.last(),
+            Some(&(Decimal::from(1001), (1000, 1)))
+        );
+        assert!(tables.american_to_decimal.contains(&(20000, dec!(201))));
+
+        let table = tables.to_lookup_table().unwrap();
+        assert_eq!(table.len(), 71);
+        let config = ConversionConfig::default().custom_lookup(table);
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(501), &config).unwrap(),
+            (500, 1)
+        );
+        assert_eq!(
+            decimal_to_fractional_custom(dec!(1.67), &config).unwrap(),
+            (4, 6)
+        );
+    }
+
+    #[test]
+    fn test_rules() {
+        let tables = TableRules::basic()
+            .american_step(dec!(5), RoundingStrategy::MidpointAwayFromZero)
+            .generate()
+            .unwrap();
+        assert!(tables.american_to_fraction.contains(&(-190, (8, 15))));
+        assert!(tables.american_to_fraction.contains(&(-110, (10, 11))));
+
+        let tables = TableRules::new(&[(1, 3), (10, 3)])
+            .decimal_places(1, RoundingStrategy::ToZero)
+            .generate()
+            .unwrap();
+        assert_eq!(
+            tables.decimal_to_fraction,
+            [(dec!(1.3), (1, 3)), (dec!(4.3), (10, 3))]
+        );
+        assert_eq!(
+            tables.american_to_decimal,
+            [(-300, dec!(1.3)), (333, dec!(4.3))]
+        );
+
+        // The first fraction of the same price is kept
+        let tables = TableRules::new(&[(4, 6), (2, 3)]).generate().unwrap();
+        assert_eq!(tables.decimal_to_fraction, [(dec!(1.67), (4, 6))]);
+        assert_eq!(tables.american_to_fraction, [(-150, (4, 6))]);
+
+        assert_eq!(
+            TableRules::new(&[(1, 0)]).generate(),
+            Err(ConversionError::DenominatorZero { num: 1 })
+        );
+        assert_eq!(
+            TableRules::new(&[(1, 1000)]).generate(),
+            Err(ConversionError::InvalidDecimal(Decimal::ONE))
+        );
+        assert_eq!(
+            TableRules::new(&[(0, 1)]).generate(),
+            Err(ConversionError::InvalidDecimal(Decimal::ONE))
+        );
+    }
+}
@@ -0,0 +1,400 @@
+use std::{collections::HashMap, fmt, hash::Hash};
+
+use rust_decimal::Decimal;
+
+use crate::{
+    LookupTable, LookupVariant, Odds,
+    lookup_tables::{
+        StaticTable, TableKey, get_american_to_decimal_extended_map, get_american_to_decimal_map,
+        get_american_to_fraction_extended_map, get_american_to_fraction_map,
+        get_decimal_to_fraction_extended_map, get_decimal_to_fraction_map,
+    },
+};
+
+/// One of the lookup tables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum LookupTableKind {
+    DecimalToFraction,
+    AmericanToFraction,
+    AmericanToDecimal,
+}
+
+/// Problem found by the lookup table validator.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum TableIssue {
+    /// Value differs from the exact price of its key by more than the tolerance.
+    Inaccurate {
+        table: LookupTableKind,
+        key: Odds,
+        value: Odds,
+        error: Decimal,
+    },
+    /// Shorter key is mapped to a longer value than the previous (longer) key.
+    /// Neighbouring keys may be mapped to the same value.
+    NotMonotonic {
+        table: LookupTableKind,
+        key: Odds,
+        value: Odds,
+        previous_key: Odds,
+        previous_value: Odds,
+    },
+    /// Tables disagree on the price of the same american odds. Decimal and fractional values
+    /// may differ up to the tolerance, fractions from different tables must be equal.
+    Mismatch {
+        key: Odds,
+        first: (LookupTableKind, Odds),
+        second: (LookupTableKind, Odds),
+        error: Decimal,
+    },
+}
+
+impl fmt::Display for TableIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TableIssue::Inaccurate {
+                table,
+                key,
+                value,
+                error,
+            } => write!(f, "{table:?}: {key} -> {value} is off by {error}"),
+            TableIssue::NotMonotonic {
+                table,
+                key,
+                value,
+                previous_key,
+                previous_value,
+            } => write!(
+                f,
+                "{table:?}: {key} -> {value} is longer than {previous_key} -> {previous_value}"
+            ),
+            TableIssue::Mismatch {
+                key,
+                first: (first_table, first),
+                second: (second_table, second),
+                error,
+            } => write!(
+                f,
+                "{key}: {first_table:?} gives {first}, {second_table:?} gives {second} (off by {error})"
+            ),
+        }
+    }
+}
+
+/// Summary of a single lookup table.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TableStats {
+    pub entries: usize,
+    /// Largest absolute difference between the decimal price of a key and of its value.
+    pub max_error: Decimal,
+}
+
+/// Result of lookup table validation.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TableReport {
+    pub decimal_to_fraction: TableStats,
+    pub american_to_fraction: TableStats,
+    pub american_to_decimal: TableStats,
+    pub issues: Vec<TableIssue>,
+}
+
+impl TableReport {
+    /// Check if no issues were found.
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Validate lookup tables used with given variant: accuracy of every entry (up to `tolerance`
+/// in decimal price), monotonicity and consistency between the tables.
+///
+/// With [`LookupVariant::Extended`] entries of the basic tables take precedence, as in conversions.
+pub fn validate_lookup_tables(variant: &LookupVariant, tolerance: Decimal) -> TableReport {
+    match variant {
+        LookupVariant::None => TableReport::default(),
+        LookupVariant::Basic => validate(
+            &collect(&[get_decimal_to_fraction_map()]),
+            &collect(&[get_american_to_fraction_map()]),
+            &collect(&[get_american_to_decimal_map()]),
+            tolerance,
+        ),
+        LookupVariant::Extended => validate(
+            &collect(&[
+                get_decimal_to_fraction_map(),
+                get_decimal_to_fraction_extended_map(),
+            ]),
+            &collect(&[
+                get_american_to_fraction_map(),
+                get_american_to_fraction_extended_map(),
+            ]),
+            &collect(&[
+                get_american_to_decimal_map(),
+                get_american_to_decimal_extended_map(),
+            ]),
+            tolerance,
+        ),
+        LookupVariant::Custom(table) => table.validate(tolerance),
+    }
+}
+
+impl LookupTable {
+    /// Validate the table, see [`validate_lookup_tables`].
+    pub fn validate(&self, tolerance: Decimal) -> TableReport {
+        validate(
+            self.decimal_to_fraction_map(),
+            self.american_to_fraction_map(),
+            self.american_to_decimal_map(),
+            tolerance,
+        )
+    }
+}
+
+/// Built-in tables as a single map, earlier tables take precedence.
+fn collect<K: TableKey + Eq + Hash, V: Copy>(tables: &[&StaticTable<K, V>]) -> HashMap<K, V> {
+    tables
+        .iter()
+        .rev()
+        .flat_map(|table| table.iter())
+        .map(|(key, value)| (key, *value))
+        .collect()
+}
+
+fn validate(
+    decimal_to_fraction: &HashMap<Decimal, (u32, u32)>,
+    american_to_fraction: &HashMap<i32, (u32, u32)>,
+    american_to_decimal: &HashMap<i32, Decimal>,
+    tolerance: Decimal,
+) -> TableReport {
+    // Tables only hold validated odds
+    let decimal = |value: &Decimal| Odds::decimal(*value).ok();
+    let american = |value: &i32| Odds::american(*value).ok();
+    let fraction = |(num, den): &(u32, u32)| Odds::fractional(*num, *den).ok();
+
+    let mut issues = Vec::new();
+    let mut check =
+        |table, entries: Vec<(Odds, Odds)>| check_table(table, entries, tolerance, &mut issues);
+    let decimal_to_fraction_stats = check(
+        LookupTableKind::DecimalToFraction,
+        entries(decimal_to_fraction, decimal, fraction),
+    );
+    let american_to_fraction_stats = check(
+        LookupTableKind::AmericanToFraction,
+        entries(american_to_fraction, american, fraction),
+    );
+    let american_to_decimal_stats = check(
+        LookupTableKind::AmericanToDecimal,
+        entries(american_to_decimal, american, decimal),
+    );
+
+    // Cross-check prices of the same american odds, from the longest
+    let mut prices: Vec<_> = american_to_decimal
+        .iter()
+        .filter_map(|(key, value)| Some((american(key)?, *key, *value)))
+        .collect();
+    prices.sort();
+    for (key, american_value, decimal_value) in prices {
+        let (Some(decimal_odds), Some(fraction_odds)) = (
+            decimal(&decimal_value),
+            american_to_fraction.get(&american_value).and_then(fraction),
+        ) else {
+            continue;
+        };
+
+        let error = (decimal_odds.exact_decimal() - fraction_odds.exact_decimal()).abs();
+        if error > tolerance {
+            issues.push(TableIssue::Mismatch {
+                key,
+                first: (LookupTableKind::AmericanToDecimal, decimal_odds),
+                second: (LookupTableKind::AmericanToFraction, fraction_odds),
+                error,
+            });
+        }
+
+        // Both fractions are read from the board for the same price
+        if let Some(decimal_fraction) = decimal_to_fraction.get(&decimal_value).and_then(fraction)
+            && decimal_fraction != fraction_odds
+        {
+            issues.push(TableIssue::Mismatch {
+                key,
+                first: (LookupTableKind::AmericanToFraction, fraction_odds),
+                second: (LookupTableKind::DecimalToFraction, decimal_fraction),
+                error: (fraction_odds.exact_decimal() - decimal_fraction.exact_decimal()).abs(),
+            });
+        }
+    }
+
+    TableReport {
+        decimal_to_fraction: decimal_to_fraction_stats,
+        american_to_fraction: american_to_fraction_stats,
+        american_to_decimal: american_to_decimal_stats,
+        issues,
+    }
+}
+
+/// Entries as odds, sorted from the longest key.
+fn entries<K, V>(
+    table: &HashMap<K, V>,
+    key: impl Fn(&K) -> Option<Odds>,
+    value: impl Fn(&V) -> Option<Odds>,
+) -> Vec<(Odds, Odds)> {
+    let mut entries: Vec<_> = table
+        .iter()
+        .filter_map(|(k, v)| Some((key(k)?, value(v)?)))
+        .collect();
+    entries.sort();
+    entries
+}
+
+fn check_table(
+    table: LookupTableKind,
+    entries: Vec<(Odds, Odds)>,
+    tolerance: Decimal,
+    issues: &mut Vec<TableIssue>,
+) -> TableStats {
+    let mut stats = TableStats {
+        entries: entries.len(),
+        max_error: Decimal::ZERO,
+    };
+
+    for (index, (key, value)) in entries.iter().copied().enumerate() {
+        let error = (key.exact_decimal() - value.exact_decimal()).abs();
+        stats.max_error = stats.max_error.max(error);
+        if error > tolerance {
+            issues.push(TableIssue::Inaccurate {
+                table,
+                key,
+                value,
+                error,
+            });
+        }
+
+        if let Some((previous_key, previous_value)) =
+            index.checked_sub(1).map(|previous| entries[previous])
+            && previous_key < key
+            && previous_value > value
+        {
+            issues.push(TableIssue::NotMonotonic {
+                table,
+                key,
+                value,
+                previous_key,
+                previous_value,
+            });
+        }
+    }
+
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_builtin_tables() {
+        let report = validate_lookup_tables(&LookupVariant::Basic, dec!(0.01));
+        assert!(report.is_valid(), "{:?}", report.issues);
+        assert_eq!(report.decimal_to_fraction.entries, 69);
+        assert_eq!(report.decimal_to_fraction.max_error, dec!(0.005));
+
+        let report = validate_lookup_tables(&LookupVariant::Extended, dec!(0.01));
+        assert!(report.is_valid(), "{:?}", report.issues);
+        assert!(report.decimal_to_fraction.entries > 69);
+        assert!(report.decimal_to_fraction.max_error > dec!(0.005));
+
+        // 4/6 is 0.0033 off 1.67
+        let report = validate_lookup_tables(&LookupVariant::Basic, dec!(0.001));
+        assert!(report.issues.contains(&TableIssue::Inaccurate {
+            table: LookupTableKind::DecimalToFraction,
+            key: Odds::decimal(dec!(1.67)).unwrap(),
+            value: Odds::fractional(4, 6).unwrap(),
+            error: dec!(1.67) - dec!(5) / dec!(3),
+        }));
+
+        assert_eq!(
+            validate_lookup_tables(&LookupVariant::None, dec!(0.01)),
+            TableReport::default()
+        );
+    }
+
+    #[test]
+    fn test_custom_table() {
+        let board = "
+            1.91,-110,10/11
+            1.8,-125,4/5
+            3.75,+275,11/4
+        ";
+        let table = LookupTable::from_csv(board).unwrap();
+        let report = table.validate(dec!(0.01));
+        assert!(report.is_valid());
+        assert_eq!(report.american_to_decimal.entries, 3);
+        assert_eq!(
+            validate_lookup_tables(&LookupVariant::Custom(table.into()), dec!(0.01)),
+            report
+        );
+
+        // Typo in the american column
+        let table = LookupTable::from_csv(&format!("{board}\n4.5,+250,7/2")).unwrap();
+        let report = table.validate(dec!(0.01));
+        assert_eq!(
+            report.issues,
+            [
+                TableIssue::Inaccurate {
+                    table: LookupTableKind::AmericanToFraction,
+                    key: Odds::american(250).unwrap(),
+                    value: Odds::fractional(7, 2).unwrap(),
+                    error: dec!(1),
+                },
+                TableIssue::NotMonotonic {
+                    table: LookupTableKind::AmericanToFraction,
+                    key: Odds::american(250).unwrap(),
+                    value: Odds::fractional(7, 2).unwrap(),
+                    previous_key: Odds::american(275).unwrap(),
+                    previous_value: Odds::fractional(11, 4).unwrap(),
+                },
+                TableIssue::Inaccurate {
+                    table: LookupTableKind::AmericanToDecimal,
+                    key: Odds::american(250).unwrap(),
+                    value: Odds::decimal(dec!(4.5)).unwrap(),
+                    error: dec!(1),
+                },
+                TableIssue::NotMonotonic {
+                    table: LookupTableKind::AmericanToDecimal,
+                    key: Odds::american(250).unwrap(),
+                    value: Odds::decimal(dec!(4.5)).unwrap(),
+                    previous_key: Odds::american(275).unwrap(),
+                    previous_value: Odds::decimal(dec!(3.75)).unwrap(),
+                },
+            ]
+        );
+        assert_eq!(
+            report.issues[1].to_string(),
+            "AmericanToFraction: +250 -> 7/2 is longer than +275 -> 11/4"
+        );
+
+        // Later entry replaces the whole entry of 1.91, so the tables stay consistent
+        let table = LookupTable::from_csv(&format!("{board}\n1.91,-111,9/10")).unwrap();
+        let report = table.validate(dec!(0.01));
+        assert!(report.issues.is_empty());
+        assert_eq!(report.decimal_to_fraction.entries, 3);
+        assert_eq!(report.american_to_fraction.entries, 3);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let table = LookupTable::from_csv("1.91,-110,10/11\n1.8,-120,4/5").unwrap();
+        let report = table.validate(dec!(0.01));
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["american_to_decimal"]["entries"], 2);
+        assert_eq!(
+            json["issues"][0]["Inaccurate"]["key"],
+            serde_json::json!({"format": "american", "value": -120})
+        );
+    }
+}
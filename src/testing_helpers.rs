@@ -1,7 +1,7 @@
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 
-use crate::ConversionError;
+use crate::{ConversionError, Fraction};
 
 // --- Helper for comparing Decimals in tests ---
 pub fn assert_decimal_eq(a: Decimal, b: Decimal) {
@@ -18,3 +18,8 @@ pub fn assert_decimal_ok_eq(a: Result<Decimal, ConversionError>, b: Decimal) {
     assert!(a.is_ok());
     assert_decimal_eq(a.unwrap(), b);
 }
+
+// --- Helper for creating fractions in tests ---
+pub fn frac(num: u32, den: u32) -> Fraction {
+    Fraction::new(num, den).unwrap()
+}
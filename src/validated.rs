@@ -0,0 +1,530 @@
+use std::fmt;
+
+use rust_decimal::{Decimal, prelude::ToPrimitive};
+
+use crate::{
+    ConversionConfig, ConversionError, decimal_to_american_custom,
+    decimal_to_precise_american_custom,
+};
+
+/// American odds, guaranteed to be at most -100 or at least +100.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "i32", into = "i32")
+)]
+pub struct AmericanOdds(i32);
+
+/// Decimal-valued american odds (f. ex. -112.5), guaranteed to be at most -100 or at least +100
+/// and convertible to whole american odds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "Decimal", into = "Decimal")
+)]
+pub struct PreciseAmericanOdds(Decimal);
+
+/// Decimal odds, guaranteed to be greater than 1.0 and convertible to american odds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "Decimal", into = "Decimal")
+)]
+pub struct DecimalOdds(Decimal);
+
+/// Fractional odds, guaranteed to have non-zero numerator and denominator and to be convertible to american odds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        try_from = "crate::fraction::FractionRepr",
+        into = "crate::fraction::FractionRepr"
+    )
+)]
+pub struct FractionalOdds {
+    num: u32,
+    den: u32,
+}
+
+impl AmericanOdds {
+    /// Validate american odds, values between -100 and +100 are rejected.
+    pub fn new(value: i32) -> Result<Self, ConversionError> {
+        match value {
+            0 => Err(ConversionError::AmericanZero),
+            -99..=99 => Err(ConversionError::InvalidAmerican(value)),
+            _ => Ok(Self(value)),
+        }
+    }
+
+    pub fn value(&self) -> i32 {
+        self.0
+    }
+}
+
+impl PreciseAmericanOdds {
+    /// Validate precise american odds, values between -100 and +100 are rejected.
+    pub fn new(value: Decimal) -> Result<Self, ConversionError> {
+        if value.is_zero() {
+            return Err(ConversionError::AmericanZero);
+        }
+        if value.abs() < Decimal::ONE_HUNDRED {
+            return Err(ConversionError::InvalidPreciseAmerican(value));
+        }
+        DecimalOdds::new(precise_american_to_decimal(value))?;
+        Ok(Self(value))
+    }
+
+    pub fn value(&self) -> Decimal {
+        self.0
+    }
+}
+
+impl DecimalOdds {
+    /// Validate decimal odds, values not greater than 1.0 or too big for american odds are rejected.
+    pub fn new(value: Decimal) -> Result<Self, ConversionError> {
+        // Fails for values not greater than 1.0 and for values which cannot be expressed in american odds
+        decimal_to_american_custom(value, &ConversionConfig::default().no_lookup())?;
+        Ok(Self(value))
+    }
+
+    pub fn value(&self) -> Decimal {
+        self.0
+    }
+}
+
+impl FractionalOdds {
+    /// Validate fractional odds, zero numerator or denominator is rejected.
+    pub fn new(num: u32, den: u32) -> Result<Self, ConversionError> {
+        if den == 0 {
+            return Err(ConversionError::DenominatorZero { num });
+        }
+        DecimalOdds::new(fraction_to_decimal(num, den))?;
+        Ok(Self { num, den })
+    }
+
+    pub fn num(&self) -> u32 {
+        self.num
+    }
+
+    pub fn den(&self) -> u32 {
+        self.den
+    }
+}
+
+fn precise_american_to_decimal(value: Decimal) -> Decimal {
+    if value > Decimal::ZERO {
+        value / Decimal::ONE_HUNDRED + Decimal::ONE
+    } else {
+        Decimal::ONE_HUNDRED / -value + Decimal::ONE
+    }
+}
+
+fn fraction_to_decimal(num: u32, den: u32) -> Decimal {
+    Decimal::from(num) / Decimal::from(den) + Decimal::ONE
+}
+
+/// Best rational approximation of a positive value with numerator and denominator fitting in `u32`.
+///
+/// Values within the range of `DecimalOdds` always give non-zero numerator.
+fn approximate_fraction(value: Decimal) -> (u32, u32) {
+    let max = u64::from(u32::MAX);
+
+    let mut a = value;
+    let (mut num, mut den) = (1u64, 0u64);
+    let (mut num_prev, mut den_prev) = (0u64, 1u64);
+
+    loop {
+        let a_floor = a.floor();
+        let Some(whole) = a_floor.to_u64() else {
+            break;
+        };
+
+        let (Some(num_next), Some(den_next)) = (
+            whole.checked_mul(num).and_then(|n| n.checked_add(num_prev)),
+            whole.checked_mul(den).and_then(|d| d.checked_add(den_prev)),
+        ) else {
+            break;
+        };
+
+        if num_next > max || den_next > max {
+            break;
+        }
+
+        num_prev = num;
+        den_prev = den;
+        num = num_next;
+        den = den_next;
+
+        let remainder = a - a_floor;
+        if remainder.is_zero() {
+            break;
+        }
+
+        a = Decimal::ONE / remainder;
+    }
+
+    (num as u32, den.max(1) as u32)
+}
+
+impl TryFrom<i32> for AmericanOdds {
+    type Error = ConversionError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<Decimal> for PreciseAmericanOdds {
+    type Error = ConversionError;
+
+    fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<Decimal> for DecimalOdds {
+    type Error = ConversionError;
+
+    fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<(u32, u32)> for FractionalOdds {
+    type Error = ConversionError;
+
+    fn try_from((num, den): (u32, u32)) -> Result<Self, Self::Error> {
+        Self::new(num, den)
+    }
+}
+
+impl From<AmericanOdds> for i32 {
+    fn from(value: AmericanOdds) -> Self {
+        value.0
+    }
+}
+
+impl From<PreciseAmericanOdds> for Decimal {
+    fn from(value: PreciseAmericanOdds) -> Self {
+        value.0
+    }
+}
+
+impl From<DecimalOdds> for Decimal {
+    fn from(value: DecimalOdds) -> Self {
+        value.0
+    }
+}
+
+impl From<FractionalOdds> for (u32, u32) {
+    fn from(value: FractionalOdds) -> Self {
+        (value.num, value.den)
+    }
+}
+
+/// Exact conversion.
+impl From<AmericanOdds> for DecimalOdds {
+    fn from(value: AmericanOdds) -> Self {
+        let american = Decimal::from(value.0);
+        if value.0 > 0 {
+            Self(american / Decimal::ONE_HUNDRED + Decimal::ONE)
+        } else {
+            Self(Decimal::ONE_HUNDRED / -american + Decimal::ONE)
+        }
+    }
+}
+
+/// Exact conversion.
+impl From<AmericanOdds> for PreciseAmericanOdds {
+    fn from(value: AmericanOdds) -> Self {
+        Self(Decimal::from(value.0))
+    }
+}
+
+/// Exact conversion.
+impl From<PreciseAmericanOdds> for DecimalOdds {
+    fn from(value: PreciseAmericanOdds) -> Self {
+        Self(precise_american_to_decimal(value.0))
+    }
+}
+
+/// Conversion rounded to whole american odds using default rounding strategy.
+impl From<PreciseAmericanOdds> for AmericanOdds {
+    fn from(value: PreciseAmericanOdds) -> Self {
+        DecimalOdds::from(value).into()
+    }
+}
+
+/// Conversion rounded to `0.01` using default rounding strategy.
+impl From<DecimalOdds> for PreciseAmericanOdds {
+    fn from(value: DecimalOdds) -> Self {
+        let config = ConversionConfig::default()
+            .no_lookup()
+            .american_step(Decimal::new(1, 2));
+        let american = decimal_to_precise_american_custom(value.0, &config)
+            .expect("decimal odds are validated to be convertible to american");
+        Self(american)
+    }
+}
+
+/// Exact conversion, reduced (f. ex. -150 gives 2/3).
+impl From<AmericanOdds> for FractionalOdds {
+    fn from(value: AmericanOdds) -> Self {
+        let american = value.0.unsigned_abs();
+        let (num, den) = if value.0 > 0 {
+            (american, 100)
+        } else {
+            (100, american)
+        };
+        let divisor = num_integer::gcd(num, den);
+
+        Self {
+            num: num / divisor,
+            den: den / divisor,
+        }
+    }
+}
+
+/// Exact conversion.
+impl From<FractionalOdds> for DecimalOdds {
+    fn from(value: FractionalOdds) -> Self {
+        Self(fraction_to_decimal(value.num, value.den))
+    }
+}
+
+/// Conversion rounded to whole american odds.
+impl From<FractionalOdds> for AmericanOdds {
+    fn from(value: FractionalOdds) -> Self {
+        DecimalOdds::from(value).into()
+    }
+}
+
+/// Conversion rounded to whole american odds using default rounding strategy.
+impl From<DecimalOdds> for AmericanOdds {
+    fn from(value: DecimalOdds) -> Self {
+        let american =
+            decimal_to_american_custom(value.0, &ConversionConfig::default().no_lookup())
+                .expect("decimal odds are validated to be convertible to american");
+        Self(american)
+    }
+}
+
+/// Closest fraction with numerator and denominator fitting in `u32` (f. ex. 1.67 gives 67/100).
+///
+/// For traditional fractions use conversions from [`Odds`](crate::Odds) which use lookup tables.
+impl From<DecimalOdds> for FractionalOdds {
+    fn from(value: DecimalOdds) -> Self {
+        let (num, den) = approximate_fraction(value.0 - Decimal::ONE);
+        Self { num, den }
+    }
+}
+
+impl fmt::Display for AmericanOdds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:+}", self.0)
+    }
+}
+
+impl fmt::Display for PreciseAmericanOdds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0 > Decimal::ZERO {
+            write!(f, "+{}", self.0)
+        } else {
+            write!(f, "{}", self.0)
+        }
+    }
+}
+
+impl fmt::Display for DecimalOdds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::Display for FractionalOdds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.num, self.den)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<crate::fraction::FractionRepr> for FractionalOdds {
+    type Error = ConversionError;
+
+    fn try_from(value: crate::fraction::FractionRepr) -> Result<Self, Self::Error> {
+        Self::new(value.num, value.den)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<FractionalOdds> for crate::fraction::FractionRepr {
+    fn from(value: FractionalOdds) -> Self {
+        Self {
+            num: value.num,
+            den: value.den,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_validation() {
+        assert!(AmericanOdds::new(-100).is_ok());
+        assert!(AmericanOdds::new(100).is_ok());
+        assert!(AmericanOdds::new(i32::MIN).is_ok());
+        assert_eq!(AmericanOdds::new(0), Err(ConversionError::AmericanZero));
+        assert_eq!(
+            AmericanOdds::new(50),
+            Err(ConversionError::InvalidAmerican(50))
+        );
+        assert_eq!(
+            AmericanOdds::try_from(-99),
+            Err(ConversionError::InvalidAmerican(-99))
+        );
+
+        assert!(PreciseAmericanOdds::new(dec!(-112.5)).is_ok());
+        assert!(PreciseAmericanOdds::new(dec!(100.0)).is_ok());
+        assert_eq!(
+            PreciseAmericanOdds::new(dec!(99.5)),
+            Err(ConversionError::InvalidPreciseAmerican(dec!(99.5)))
+        );
+        assert_eq!(
+            PreciseAmericanOdds::try_from(dec!(0.0)),
+            Err(ConversionError::AmericanZero)
+        );
+
+        assert!(DecimalOdds::new(dec!(1.001)).is_ok());
+        assert_eq!(
+            DecimalOdds::new(dec!(1.0)),
+            Err(ConversionError::InvalidDecimal(dec!(1.0)))
+        );
+        assert_eq!(
+            DecimalOdds::try_from(dec!(0.5)),
+            Err(ConversionError::InvalidDecimal(dec!(0.5)))
+        );
+        // Too long to be expressed in american odds
+        assert_eq!(
+            DecimalOdds::new(dec!(100000000)),
+            Err(ConversionError::DecimalOverflow(dec!(100000000)))
+        );
+        assert_eq!(
+            DecimalOdds::new(dec!(1.0000000000000000000000000001)),
+            Err(ConversionError::DecimalOverflow(dec!(
+                1.0000000000000000000000000001
+            )))
+        );
+
+        assert!(FractionalOdds::new(1, 1000).is_ok());
+        assert_eq!(
+            FractionalOdds::new(5, 0),
+            Err(ConversionError::DenominatorZero { num: 5 })
+        );
+        assert_eq!(
+            FractionalOdds::try_from((0, 1)),
+            Err(ConversionError::InvalidDecimal(dec!(1)))
+        );
+        assert!(FractionalOdds::new(u32::MAX, 1).is_err());
+    }
+
+    #[test]
+    fn test_exact_conversions() {
+        let american = AmericanOdds::new(-150).unwrap();
+        assert_eq!(
+            DecimalOdds::from(american).value().round_dp(4),
+            dec!(1.6667)
+        );
+        assert_eq!(<(u32, u32)>::from(FractionalOdds::from(american)), (2, 3));
+
+        let american = AmericanOdds::new(250).unwrap();
+        assert_eq!(DecimalOdds::from(american).value(), dec!(3.5));
+        assert_eq!(<(u32, u32)>::from(FractionalOdds::from(american)), (5, 2));
+
+        let fractional = FractionalOdds::new(4, 6).unwrap();
+        assert_eq!(
+            DecimalOdds::from(fractional).value().round_dp(4),
+            dec!(1.6667)
+        );
+        assert_eq!(AmericanOdds::from(fractional).value(), -150);
+
+        // Extremes of american odds
+        let american = AmericanOdds::new(i32::MIN).unwrap();
+        assert_eq!(
+            <(u32, u32)>::from(FractionalOdds::from(american)),
+            (25, 536870912)
+        );
+        assert_eq!(
+            AmericanOdds::from(DecimalOdds::from(american)).value(),
+            i32::MIN
+        );
+
+        let american = AmericanOdds::new(i32::MAX).unwrap();
+        assert_eq!(
+            AmericanOdds::from(DecimalOdds::from(american)).value(),
+            i32::MAX
+        );
+    }
+
+    #[test]
+    fn test_precise_american_conversions() {
+        let precise = PreciseAmericanOdds::new(dec!(137.5)).unwrap();
+        assert_eq!(DecimalOdds::from(precise).value(), dec!(2.375));
+        assert_eq!(AmericanOdds::from(precise).value(), 138);
+
+        let precise = PreciseAmericanOdds::new(dec!(-112.5)).unwrap();
+        assert_eq!(AmericanOdds::from(precise).value(), -113);
+
+        let precise = PreciseAmericanOdds::from(AmericanOdds::new(-110).unwrap());
+        assert_eq!(precise.value(), dec!(-110));
+
+        // Rounded to 0.01
+        let decimal = DecimalOdds::new(dec!(1.67)).unwrap();
+        assert_eq!(PreciseAmericanOdds::from(decimal).value(), dec!(-149.25));
+    }
+
+    #[test]
+    fn test_rounded_conversions() {
+        let decimal = DecimalOdds::new(dec!(1.67)).unwrap();
+        assert_eq!(AmericanOdds::from(decimal).value(), -149);
+        assert_eq!(<(u32, u32)>::from(FractionalOdds::from(decimal)), (67, 100));
+
+        let decimal = DecimalOdds::new(dec!(3.5)).unwrap();
+        assert_eq!(AmericanOdds::from(decimal).value(), 250);
+        assert_eq!(<(u32, u32)>::from(FractionalOdds::from(decimal)), (5, 2));
+
+        // Repeating fractions are approximated
+        let decimal = DecimalOdds::from(FractionalOdds::new(1, 3).unwrap());
+        assert_eq!(<(u32, u32)>::from(FractionalOdds::from(decimal)), (1, 3));
+
+        // Extremely short price still has non-zero numerator
+        let decimal = DecimalOdds::new(dec!(1.0000001)).unwrap();
+        assert_eq!(
+            <(u32, u32)>::from(FractionalOdds::from(decimal)),
+            (1, 10000000)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(AmericanOdds::new(150).unwrap().to_string(), "+150");
+        assert_eq!(AmericanOdds::new(-110).unwrap().to_string(), "-110");
+        assert_eq!(
+            PreciseAmericanOdds::new(dec!(137.5)).unwrap().to_string(),
+            "+137.5"
+        );
+        assert_eq!(
+            PreciseAmericanOdds::new(dec!(-112.5)).unwrap().to_string(),
+            "-112.5"
+        );
+        assert_eq!(DecimalOdds::new(dec!(3.50)).unwrap().to_string(), "3.50");
+        assert_eq!(FractionalOdds::new(5, 2).unwrap().to_string(), "5/2");
+    }
+}